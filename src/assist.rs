@@ -0,0 +1,133 @@
+use biome_configuration::Configuration;
+use biome_diagnostics::Severity;
+use serde_json::Value;
+
+use crate::registry::RuleInfo;
+
+/// Name of the group containing the assist actions in the registry.
+pub const ASSIST_GROUP: &str = "source";
+
+/// The `assist` section of the configuration, which is independent from the linter rules.
+pub struct AssistSettings {
+    value: Value,
+}
+
+impl AssistSettings {
+    pub fn new(config: &Configuration) -> Self {
+        Self {
+            value: serde_json::to_value(&config.assist).unwrap_or_default(),
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.value
+            .get("enabled")
+            .and_then(Value::as_bool)
+            .unwrap_or(true)
+    }
+
+    /// Resolves the severity of the assist action, or `None` if it is not enabled.
+    ///
+    /// Biome reports the actions not applied as errors on `biome check`, so `on` maps to an error.
+    pub fn get_configured_severity(&self, action: &str, info: &RuleInfo) -> Option<Severity> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let actions = self.value.get("actions");
+        let level = match actions.and_then(|actions| actions.get(ASSIST_GROUP)) {
+            Some(Value::String(level)) => Some(level.as_str()),
+            Some(group) => match group.get(action) {
+                Some(Value::Object(action)) => action.get("level").and_then(Value::as_str),
+                Some(action) => action.as_str(),
+                None => None,
+            },
+            None => None,
+        };
+
+        match level {
+            Some("off") => None,
+            Some("warn") => Some(Severity::Warning),
+            Some("info") => Some(Severity::Information),
+            Some(_) => Some(Severity::Error),
+            None => {
+                let recommended = actions
+                    .and_then(|actions| actions.get("recommended"))
+                    .and_then(Value::as_bool)
+                    .unwrap_or(true);
+
+                (recommended && info.recommended).then_some(Severity::Error)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::eslint::build_eslint_config;
+    use crate::testing::{config, options, registry};
+
+    fn info(recommended: bool) -> RuleInfo {
+        RuleInfo {
+            severity: Severity::Information,
+            recommended,
+            sources: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn severity_is_read_from_the_assist_section() {
+        let assist = AssistSettings {
+            value: json!({ "actions": { "source": { "useSortedKeys": "warn", "organizeImports": { "level": "off" } } } }),
+        };
+
+        assert_eq!(
+            assist.get_configured_severity("useSortedKeys", &info(false)),
+            Some(Severity::Warning)
+        );
+        assert_eq!(
+            assist.get_configured_severity("organizeImports", &info(true)),
+            None
+        );
+    }
+
+    #[test]
+    fn recommended_actions_are_errors_by_default() {
+        let assist = AssistSettings::new(&config("{}"));
+
+        assert_eq!(
+            assist.get_configured_severity("organizeImports", &info(true)),
+            Some(Severity::Error)
+        );
+        assert_eq!(
+            assist.get_configured_severity("useSortedKeys", &info(false)),
+            None
+        );
+
+        let assist = AssistSettings::new(&config(r#"{ "assist": { "enabled": false } }"#));
+        assert_eq!(
+            assist.get_configured_severity("organizeImports", &info(true)),
+            None
+        );
+    }
+
+    #[test]
+    fn actions_are_ejected_at_their_levels() {
+        let registry = registry(&[(
+            ASSIST_GROUP,
+            "useSortedKeys",
+            Severity::Information,
+            &["perfectionist/sort-objects"],
+        )]);
+        let config =
+            config(r#"{ "assist": { "actions": { "source": { "useSortedKeys": "on" } } } }"#);
+
+        let eslint_config = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .config;
+        assert!(eslint_config.contains(r#""perfectionist/sort-objects": "error""#));
+    }
+}
@@ -0,0 +1,195 @@
+use std::fs;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use crate::diagnostics::warning;
+use crate::options::EjectOptions;
+
+/// Name of the file to record the fingerprint of the last passing check in.
+const CACHE_FILE: &str = ".biome-eject-cache";
+
+/// Files in the directory which the result of the check always depends on, besides the ones
+/// recorded by the check.
+const TRACKED_FILES: &[&str] = &[".biome-eject.json", "package.json"];
+
+/// Fingerprints the version of the tool, the options, and the files the check depends on.
+///
+/// The files are the ones recorded by the last passing check, i.e. the Biome configs along with the
+/// ones extended transitively, the `.gitignore` respected by Biome, and the files written. Any of
+/// them changing the set of the files changes the content of another, e.g. the config adding a
+/// config to `extends`.
+pub fn fingerprint(dir: &Path, options: &EjectOptions, files: &[PathBuf]) -> String {
+    let mut hasher = DefaultHasher::new();
+
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+    format!("{options:?}").hash(&mut hasher);
+
+    for file in TRACKED_FILES
+        .iter()
+        .map(|file| dir.join(file))
+        .chain(files.iter().cloned())
+    {
+        file.hash(&mut hasher);
+        fs::read(file).ok().hash(&mut hasher);
+    }
+
+    format!("{:016x}", hasher.finish())
+}
+
+/// Whether the last passing check in the directory had the same fingerprint, over the files it
+/// recorded.
+pub fn is_fresh(dir: &Path, options: &EjectOptions) -> bool {
+    let Ok(cache) = fs::read_to_string(dir.join(CACHE_FILE)) else {
+        return false;
+    };
+
+    let mut lines = cache.lines();
+    let Some(stored) = lines.next() else {
+        return false;
+    };
+
+    let files: Vec<_> = lines.map(PathBuf::from).collect();
+    stored == fingerprint(dir, options, &files)
+}
+
+/// Records the fingerprint of the passing check, followed by the files it depends on.
+pub fn store(dir: &Path, options: &EjectOptions, files: &[PathBuf]) {
+    let mut cache = fingerprint(dir, options, files) + "\n";
+    for file in files {
+        cache += &format!("{}\n", file.display());
+    }
+
+    if let Err(e) = fs::write(dir.join(CACHE_FILE), cache) {
+        warning!("could not write {CACHE_FILE}: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use biome_diagnostics::Severity;
+
+    use super::*;
+    use crate::package::{check_packages, eject_package};
+    use crate::testing::{options, registry, temp_dir};
+
+    #[test]
+    fn second_check_hits_the_cache() {
+        let dir = temp_dir("cache");
+        fs::write(
+            dir.join("biome.json"),
+            r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#,
+        )
+        .unwrap();
+
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let options = options();
+        for output in eject_package(&registry, &dir, &options).unwrap().outputs {
+            output.write();
+        }
+
+        let packages = [dir.clone()];
+        let concurrency = NonZeroUsize::MIN;
+        assert!(!is_fresh(&dir, &options));
+
+        assert!(check_packages(&registry, &packages, &options, concurrency, true).unwrap());
+        assert!(is_fresh(&dir, &options));
+
+        // Nothing is stored without the cache
+        fs::remove_file(dir.join(CACHE_FILE)).unwrap();
+        assert!(check_packages(&registry, &packages, &options, concurrency, false).unwrap());
+        assert!(!dir.join(CACHE_FILE).exists());
+    }
+
+    #[test]
+    fn changes_of_the_inputs_invalidate_the_cache() {
+        let dir = temp_dir("cache-invalidated");
+        fs::write(dir.join("biome.json"), "{}").unwrap();
+
+        let options = options();
+        let files = [dir.join("biome.json")];
+        store(&dir, &options, &files);
+        assert!(is_fresh(&dir, &options));
+
+        let strict = EjectOptions {
+            promote_warnings: true,
+            ..options.clone()
+        };
+        assert!(!is_fresh(&dir, &strict));
+
+        fs::write(
+            dir.join("biome.json"),
+            r#"{ "linter": { "enabled": false } }"#,
+        )
+        .unwrap();
+        assert!(!is_fresh(&dir, &options));
+    }
+
+    #[test]
+    fn every_file_read_or_written_invalidates_the_cache() {
+        let dir = temp_dir("cache-transitive");
+        fs::write(
+            dir.join("biome.json"),
+            r#"{
+                "extends": ["base.json"],
+                "vcs": { "enabled": true, "clientKind": "git", "useIgnoreFile": true }
+            }"#,
+        )
+        .unwrap();
+        fs::write(dir.join("base.json"), r#"{ "extends": ["shared.json"] }"#).unwrap();
+        fs::write(
+            dir.join("shared.json"),
+            r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#,
+        )
+        .unwrap();
+        fs::write(dir.join(".gitignore"), "/dist\n").unwrap();
+
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let options = EjectOptions {
+            output: "config/eslint.config.mjs".into(),
+            ..options()
+        };
+
+        let packages = [dir.clone()];
+        let check = || {
+            for output in eject_package(&registry, &dir, &options).unwrap().outputs {
+                output.write();
+            }
+
+            assert!(
+                check_packages(&registry, &packages, &options, NonZeroUsize::MIN, true).unwrap()
+            );
+            assert!(is_fresh(&dir, &options));
+        };
+
+        // Extended through another config
+        check();
+        fs::write(
+            dir.join("shared.json"),
+            r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "warn" } } } }"#,
+        )
+        .unwrap();
+        assert!(!is_fresh(&dir, &options));
+
+        // Respected by Biome
+        check();
+        fs::write(dir.join(".gitignore"), "/dist\n/build\n").unwrap();
+        assert!(!is_fresh(&dir, &options));
+
+        // Written next to the path passed by --output
+        check();
+        fs::write(dir.join("config/eslint.config.mjs"), "export default [];\n").unwrap();
+        assert!(!is_fresh(&dir, &options));
+    }
+}
@@ -0,0 +1,218 @@
+use biome_configuration::Configuration;
+use serde_json::{Value, json};
+
+/// Returns the top-level keys of the raw config which are lost in the parsed config.
+///
+/// The parsed config drops the fields unknown to the linked version of Biome, so they cannot be
+/// ejected without being noticed otherwise.
+pub fn unrepresented_keys(raw: &Value, config: &Configuration) -> Vec<String> {
+    let Some(raw) = raw.as_object() else {
+        return Vec::new();
+    };
+
+    let parsed = serde_json::to_value(config).unwrap_or_default();
+
+    raw.keys()
+        .filter(|key| parsed.get(key.as_str()).is_none())
+        .filter(|key| !raw[key.as_str()].is_null())
+        .cloned()
+        .collect()
+}
+
+/// Removes the top-level keys of the raw config unknown to the linked version of Biome, returning
+/// them.
+///
+/// Biome rejects the unknown keys, so a field newer than the linked version would otherwise fail
+/// the whole config rather than being warned about.
+pub fn remove_unknown_keys(raw: &mut Value) -> Vec<String> {
+    let Some(raw) = raw.as_object_mut() else {
+        return Vec::new();
+    };
+
+    // Every known key is optional, so only the unknown ones fail even without their values
+    let unknown: Vec<_> = raw
+        .keys()
+        .filter(|key| {
+            serde_json::from_value::<Configuration>(json!({ key.as_str(): null })).is_err()
+        })
+        .cloned()
+        .collect();
+
+    for key in &unknown {
+        raw.remove(key);
+    }
+
+    unknown
+}
+
+/// Merges the other raw config into the base one, as Biome merges the configs in `extends`.
+///
+/// The objects are merged key by key, while the other values including the arrays are replaced.
+pub fn merge(base: &mut Value, other: Value) {
+    match (base, other) {
+        (Value::Object(base), Value::Object(other)) => {
+            for (key, value) in other {
+                match base.get_mut(&key) {
+                    Some(base) => merge(base, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, other) => *base = other,
+    }
+}
+
+/// Strips the comments and the trailing commas of JSONC, leaving the rest to serde_json.
+///
+/// The line breaks in the comments are kept, so the lines reported on the syntax errors still
+/// point into the original file.
+pub fn strip_jsonc(source: &str) -> String {
+    let mut output = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+
+    // Position of the last comma in the output, removed if followed by a closing bracket
+    let mut pending_comma = None;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                pending_comma = None;
+                output.push(c);
+
+                while let Some(c) = chars.next() {
+                    output.push(c);
+                    match c {
+                        '\\' => output.extend(chars.next()),
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => while chars.next_if(|c| *c != '\n').is_some() {},
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+
+                let mut previous = None;
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        output.push(c);
+                    } else if previous == Some('*') && c == '/' {
+                        break;
+                    }
+
+                    previous = Some(c);
+                }
+            }
+            ',' => {
+                pending_comma = Some(output.len());
+                output.push(c);
+            }
+            '}' | ']' => {
+                if let Some(index) = pending_comma.take() {
+                    output.replace_range(index..index + 1, " ");
+                }
+
+                output.push(c);
+            }
+            c if c.is_whitespace() => output.push(c),
+            c => {
+                pending_comma = None;
+                output.push(c);
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_keys_are_removed() {
+        let mut raw = json!({
+            "formatter": { "indentWidth": 4 },
+            "futureFeature": { "enabled": true },
+        });
+
+        assert_eq!(remove_unknown_keys(&mut raw), ["futureFeature"]);
+        assert_eq!(raw, json!({ "formatter": { "indentWidth": 4 } }));
+
+        let config: Configuration = serde_json::from_value(raw.clone()).unwrap();
+        assert!(unrepresented_keys(&raw, &config).is_empty());
+    }
+
+    /// Parses the JSONC as Biome would, through [`strip_jsonc`].
+    fn parse_jsonc(source: &str) -> Value {
+        serde_json::from_str(&strip_jsonc(source)).unwrap()
+    }
+
+    #[test]
+    fn comments_are_stripped() {
+        let raw = parse_jsonc(
+            r#"{
+                // Line comment
+                "formatter": { /* block comment */ "indentWidth": 4 } /* multi-line
+                block comment */
+            }"#,
+        );
+
+        assert_eq!(raw, json!({ "formatter": { "indentWidth": 4 } }));
+    }
+
+    #[test]
+    fn strings_containing_comment_markers_are_kept() {
+        let raw = parse_jsonc(
+            r#"{
+                "$schema": "https://biomejs.dev/schemas/2.0.0/schema.json", // Trailing comment
+                "files": { "includes": ["src/**/*.ts", "!**/* /*", "\"// not a comment"] }
+            }"#,
+        );
+
+        assert_eq!(
+            raw,
+            json!({
+                "$schema": "https://biomejs.dev/schemas/2.0.0/schema.json",
+                "files": { "includes": ["src/**/*.ts", "!**/* /*", "\"// not a comment"] }
+            })
+        );
+    }
+
+    #[test]
+    fn trailing_commas_are_stripped() {
+        let raw = parse_jsonc(
+            r#"{
+                "files": { "includes": ["src", "test",], },
+                "formatter": { "indentWidth": 4, // Trailing comment
+                },
+                "linter": { "enabled": true, /* block comment */ },
+            }"#,
+        );
+
+        assert_eq!(
+            raw,
+            json!({
+                "files": { "includes": ["src", "test"] },
+                "formatter": { "indentWidth": 4 },
+                "linter": { "enabled": true }
+            })
+        );
+    }
+
+    #[test]
+    fn lines_are_kept() {
+        let source = "{\n  /* a\n  b */\n  \"linter\": {}, // c\n}\n";
+
+        assert_eq!(strip_jsonc(source).lines().count(), source.lines().count());
+    }
+
+    #[test]
+    fn commas_in_strings_are_kept() {
+        let raw = parse_jsonc(r#"{ "files": { "includes": [",]", ",}"] } }"#);
+
+        assert_eq!(raw, json!({ "files": { "includes": [",]", ",}"] } }));
+    }
+}
@@ -0,0 +1,287 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::diagnostics::warning;
+
+/// Sections of the `package.json` declaring the packages, any of which is left as-is.
+const DEPENDENCY_SECTIONS: &[&str] = &[
+    "dependencies",
+    "devDependencies",
+    "peerDependencies",
+    "optionalDependencies",
+];
+
+/// Lockfiles of the package managers, searched in the order.
+const LOCKFILES: &[(&str, PackageManager)] = &[
+    ("pnpm-lock.yaml", PackageManager::Pnpm),
+    ("yarn.lock", PackageManager::Yarn),
+    ("bun.lock", PackageManager::Bun),
+    ("bun.lockb", PackageManager::Bun),
+    ("package-lock.json", PackageManager::Npm),
+];
+
+/// Package manager of the project, detected by its lockfile.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PackageManager {
+    #[default]
+    Npm,
+    Pnpm,
+    Yarn,
+    Bun,
+}
+
+impl PackageManager {
+    /// Detects the package manager by the lockfile in the directory or any of its ancestors,
+    /// falling back to npm.
+    ///
+    /// The ancestors are searched as the workspaces share the lockfile at the root.
+    pub fn detect(dir: &Path) -> Self {
+        let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+
+        dir.ancestors()
+            .find_map(|ancestor| {
+                LOCKFILES.iter().find_map(|(lockfile, manager)| {
+                    ancestor.join(lockfile).is_file().then_some(*manager)
+                })
+            })
+            .unwrap_or_default()
+    }
+
+    /// Command installing the packages as the dev dependencies, resolving their versions.
+    pub fn install_command(&self, packages: &[&str]) -> String {
+        let command = match self {
+            Self::Npm => "npm install -D",
+            Self::Pnpm => "pnpm add -D",
+            Self::Yarn => "yarn add -D",
+            Self::Bun => "bun add -d",
+        };
+
+        format!("{command} {}", packages.join(" "))
+    }
+}
+
+/// Adds the packages missing in the `package.json` to its `devDependencies`, returning the
+/// updated source and the packages added.
+///
+/// The packages declared in any section are never touched, not to overwrite the versions pinned.
+/// The rest are added at the end with `*`, to be resolved by the install command, leaving the
+/// order of the keys and the indentation as-is.
+pub fn add_dev_dependencies<'a>(
+    source: &str,
+    packages: &BTreeSet<&'a str>,
+) -> (String, Vec<&'a str>) {
+    let Ok(package) = serde_json::from_str::<Value>(source) else {
+        warning!("package.json is not a valid JSON, skipping the dependencies");
+        return (source.to_string(), Vec::new());
+    };
+
+    let missing: Vec<_> = packages
+        .iter()
+        .copied()
+        .filter(|name| {
+            DEPENDENCY_SECTIONS.iter().all(|section| {
+                package
+                    .get(section)
+                    .and_then(|dependencies| dependencies.get(name))
+                    .is_none()
+            })
+        })
+        .collect();
+
+    let Some(root_start) = source.find('{') else {
+        return (source.to_string(), Vec::new());
+    };
+
+    let indent = detect_indent(&source[root_start..]);
+    let entries: Vec<_> = missing
+        .iter()
+        .map(|name| {
+            format!(
+                "{indent}{indent}{}: \"*\"",
+                serde_json::to_string(name).unwrap()
+            )
+        })
+        .collect();
+
+    if entries.is_empty() {
+        return (source.to_string(), missing);
+    }
+
+    let mut updated = source.to_string();
+
+    // The section is searched by its key, the same as the scripts
+    let section = package
+        .get("devDependencies")
+        .and_then(|_| source.find("\"devDependencies\""))
+        .and_then(|key| Some(key + source[key..].find('{')?));
+
+    match section {
+        Some(start) => {
+            let Some(end) = object_end(source, start) else {
+                return (source.to_string(), Vec::new());
+            };
+
+            insert_members(&mut updated, start, end, &indent, &entries.join(",\n"));
+        }
+        None => {
+            let Some(end) = object_end(source, root_start) else {
+                return (source.to_string(), Vec::new());
+            };
+
+            let section = format!(
+                "{indent}\"devDependencies\": {{\n{}\n{indent}}}",
+                entries.join(",\n")
+            );
+
+            insert_members(&mut updated, root_start, end, "", &section);
+        }
+    }
+
+    (updated, missing)
+}
+
+/// Inserts the members after the last one of the object, between the braces at the indices.
+fn insert_members(source: &mut String, start: usize, end: usize, indent: &str, members: &str) {
+    let inner = &source[start + 1..end];
+
+    if inner.trim().is_empty() {
+        source.replace_range(start + 1..end, &format!("\n{members}\n{indent}"));
+    } else {
+        let last = start + 1 + inner.trim_end().len();
+        source.insert_str(last, &format!(",\n{members}"));
+    }
+}
+
+/// Indentation of the members in the object, falling back to two spaces.
+fn detect_indent(object: &str) -> String {
+    object
+        .lines()
+        .nth(1)
+        .map(|line| &line[..line.len() - line.trim_start().len()])
+        .filter(|indent| !indent.is_empty())
+        .unwrap_or("  ")
+        .to_string()
+}
+
+/// Finds the closing brace of the object opening at the index, skipping the strings.
+fn object_end(source: &str, start: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (index, c) in source[start..].char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '{' | '[' => depth += 1,
+            '}' | ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(start + index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::testing::temp_dir;
+
+    #[test]
+    fn missing_packages_are_appended_to_dev_dependencies() {
+        let source = r#"{
+    "name": "app",
+    "devDependencies": {
+        "eslint": "^9.0.0"
+    }
+}
+"#;
+        let packages = BTreeSet::from(["eslint", "eslint-plugin-unicorn", "typescript-eslint"]);
+
+        let (updated, added) = add_dev_dependencies(source, &packages);
+        assert_eq!(added, ["eslint-plugin-unicorn", "typescript-eslint"]);
+        assert_eq!(
+            updated,
+            r#"{
+    "name": "app",
+    "devDependencies": {
+        "eslint": "^9.0.0",
+        "eslint-plugin-unicorn": "*",
+        "typescript-eslint": "*"
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn dev_dependencies_are_added_unless_declared_elsewhere() {
+        let source = r#"{
+  "name": "app",
+  "dependencies": {
+    "typescript-eslint": "^8.0.0"
+  }
+}
+"#;
+        let packages = BTreeSet::from(["eslint", "typescript-eslint"]);
+
+        let (updated, added) = add_dev_dependencies(source, &packages);
+        assert_eq!(added, ["eslint"]);
+        assert_eq!(
+            updated,
+            r#"{
+  "name": "app",
+  "dependencies": {
+    "typescript-eslint": "^8.0.0"
+  },
+  "devDependencies": {
+    "eslint": "*"
+  }
+}
+"#
+        );
+
+        // Nothing to add
+        let (updated, added) = add_dev_dependencies(&updated, &packages);
+        assert!(added.is_empty());
+        assert!(updated.contains(r#""typescript-eslint": "^8.0.0""#));
+    }
+
+    #[test]
+    fn package_manager_is_detected_by_the_lockfile() {
+        let root = temp_dir("package-manager");
+        let package = root.join("packages").join("web");
+        fs::create_dir_all(&package).unwrap();
+
+        assert_eq!(PackageManager::detect(&package), PackageManager::Npm);
+
+        fs::write(root.join("pnpm-lock.yaml"), "").unwrap();
+        assert_eq!(PackageManager::detect(&package), PackageManager::Pnpm);
+
+        fs::write(package.join("bun.lockb"), "").unwrap();
+        assert_eq!(PackageManager::detect(&package), PackageManager::Bun);
+
+        assert_eq!(
+            PackageManager::Pnpm.install_command(&["eslint", "typescript-eslint"]),
+            "pnpm add -D eslint typescript-eslint"
+        );
+    }
+}
@@ -0,0 +1,140 @@
+/// ESLint core rules deprecated in favor of `@stylistic/eslint-plugin`.
+///
+/// See https://eslint.org/blog/2023/10/deprecating-formatting-rules/
+const DEPRECATED_FORMATTING_RULES: &[&str] = &[
+    "array-bracket-newline",
+    "array-bracket-spacing",
+    "array-element-newline",
+    "arrow-parens",
+    "arrow-spacing",
+    "block-spacing",
+    "brace-style",
+    "comma-dangle",
+    "comma-spacing",
+    "comma-style",
+    "computed-property-spacing",
+    "dot-location",
+    "eol-last",
+    "func-call-spacing",
+    "function-call-argument-newline",
+    "function-paren-newline",
+    "generator-star-spacing",
+    "implicit-arrow-linebreak",
+    "indent",
+    "jsx-quotes",
+    "key-spacing",
+    "keyword-spacing",
+    "linebreak-style",
+    "lines-around-comment",
+    "lines-between-class-members",
+    "max-len",
+    "max-statements-per-line",
+    "multiline-ternary",
+    "new-parens",
+    "newline-per-chained-call",
+    "no-confusing-arrow",
+    "no-extra-parens",
+    "no-extra-semi",
+    "no-floating-decimal",
+    "no-mixed-operators",
+    "no-mixed-spaces-and-tabs",
+    "no-multi-spaces",
+    "no-multiple-empty-lines",
+    "no-tabs",
+    "no-trailing-spaces",
+    "no-whitespace-before-property",
+    "nonblock-statement-body-position",
+    "object-curly-newline",
+    "object-curly-spacing",
+    "object-property-newline",
+    "one-var-declaration-per-line",
+    "operator-linebreak",
+    "padded-blocks",
+    "padding-line-between-statements",
+    "quote-props",
+    "quotes",
+    "rest-spread-spacing",
+    "semi",
+    "semi-spacing",
+    "semi-style",
+    "space-before-blocks",
+    "space-before-function-paren",
+    "space-in-parens",
+    "space-infix-ops",
+    "space-unary-ops",
+    "spaced-comment",
+    "switch-colon-spacing",
+    "template-curly-spacing",
+    "template-tag-spacing",
+    "wrap-iife",
+    "wrap-regex",
+    "yield-star-spacing",
+];
+
+/// Deprecated rules which got a different name on the move to `@stylistic`.
+const RENAMED_RULES: &[(&str, &str)] = &[("func-call-spacing", "function-call-spacing")];
+
+/// Returns the namespaced `@stylistic` replacement of the deprecated ESLint core rule, if any.
+pub fn stylistic_replacement(rule: &str) -> Option<String> {
+    if !DEPRECATED_FORMATTING_RULES.contains(&rule) {
+        return None;
+    }
+
+    let replacement = RENAMED_RULES
+        .iter()
+        .find_map(|(deprecated, renamed)| (*deprecated == rule).then_some(*renamed))
+        .unwrap_or(rule);
+
+    Some(format!("@stylistic/{replacement}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use biome_diagnostics::Severity;
+
+    use super::*;
+    use crate::eslint::build_eslint_config;
+    use crate::options::EjectOptions;
+    use crate::testing::{config, options, registry};
+
+    #[test]
+    fn deprecated_rules_are_replaced() {
+        assert_eq!(
+            stylistic_replacement("semi").as_deref(),
+            Some("@stylistic/semi")
+        );
+        assert_eq!(
+            stylistic_replacement("func-call-spacing").as_deref(),
+            Some("@stylistic/function-call-spacing")
+        );
+        assert_eq!(stylistic_replacement("eqeqeq"), None);
+    }
+
+    #[test]
+    fn deprecated_rules_are_rewritten_if_preferred() {
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-extra-semi"],
+        )]);
+        let config =
+            config(r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#);
+
+        let eslint_config = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .config;
+        assert!(eslint_config.contains(r#""no-extra-semi": "error""#));
+
+        let options = EjectOptions {
+            prefer_stylistic: true,
+            ..options()
+        };
+        let eslint_config = build_eslint_config(&registry, &config, &options)
+            .unwrap()
+            .config;
+        assert!(eslint_config.contains(r#""@stylistic/no-extra-semi": "error""#));
+        assert!(eslint_config.contains(r#"import stylistic from "@stylistic/eslint-plugin";"#));
+        assert!(!eslint_config.contains(r#""no-extra-semi""#));
+    }
+}
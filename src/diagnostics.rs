@@ -0,0 +1,118 @@
+use std::fmt::{self, Arguments, Display};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::options::WarningFormat;
+
+static FORMAT: OnceLock<WarningFormat> = OnceLock::new();
+
+/// Sets the format of the warnings and the errors, detecting GitHub Actions if not given.
+pub fn init(format: Option<WarningFormat>) {
+    let format = format.unwrap_or_else(|| {
+        if std::env::var("GITHUB_ACTIONS").is_ok_and(|value| value == "true") {
+            WarningFormat::Github
+        } else {
+            WarningFormat::Text
+        }
+    });
+
+    let _ = FORMAT.set(format);
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Level {
+    Warning,
+    Error,
+}
+
+impl Level {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Prints the diagnostic to stderr, annotating the file if any.
+pub fn emit(level: Level, file: Option<&Path>, message: Arguments) {
+    match FORMAT.get().copied().unwrap_or_default() {
+        WarningFormat::Text => eprintln!("{}: {message}", level.as_str()),
+        // ::warning file=biome.json::message
+        WarningFormat::Github => {
+            let file = file
+                .map(|file| format!(" file={}", escape_property(&file.display().to_string())))
+                .unwrap_or_default();
+
+            eprintln!(
+                "::{}{file}::{}",
+                level.as_str(),
+                escape_data(&message.to_string())
+            );
+        }
+    }
+}
+
+/// Prints the error to stderr and exits with the failure, instead of panicking with a backtrace.
+///
+/// Only the CLI exits, the library returns an [`Error`] to let the embedders handle it.
+pub fn fail(file: Option<&Path>, message: Arguments) -> ! {
+    emit(Level::Error, file, message);
+    std::process::exit(1)
+}
+
+/// Error on ejecting, annotating the file if any, e.g. a Biome config which could not be parsed.
+#[derive(Debug)]
+pub struct Error {
+    pub file: Option<PathBuf>,
+    pub message: String,
+}
+
+impl Error {
+    pub fn new(file: Option<&Path>, message: impl Display) -> Self {
+        Self {
+            file: file.map(Path::to_path_buf),
+            message: message.to_string(),
+        }
+    }
+
+    /// Prints the error to stderr and exits with the failure.
+    pub fn fail(&self) -> ! {
+        fail(self.file.as_deref(), format_args!("{}", self.message))
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Escapes the message of the workflow command, which is terminated by a line break.
+fn escape_data(data: &str) -> String {
+    data.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escapes the property of the workflow command, which is also delimited by `:` and `,`.
+fn escape_property(property: &str) -> String {
+    escape_data(property)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+#[macro_export]
+macro_rules! warning {
+    ($($arg:tt)*) => {
+        $crate::diagnostics::emit(
+            $crate::diagnostics::Level::Warning,
+            None,
+            format_args!($($arg)*),
+        )
+    };
+}
+
+pub use crate::warning;
@@ -1,6 +1,5 @@
 use std::collections::{BTreeMap, BTreeSet};
-use std::fs::File;
-use std::io::Write;
+use std::path::{Component, Path};
 
 use biome_analyze::{RuleFilter, RuleSource};
 use biome_configuration::analyzer::{GroupPlainConfiguration, RuleGroupExt, SeverityOrGroup};
@@ -9,14 +8,29 @@ use biome_diagnostics::Severity;
 use biome_js_factory::make;
 use biome_js_formatter::context::JsFormatOptions;
 use biome_js_syntax::{
-    AnyJsCallArgument, AnyJsExpression, AnyJsObjectMember, JsImport, JsSyntaxToken, T,
+    AnyJsArrayElement, AnyJsCallArgument, AnyJsExpression, AnyJsObjectMember, JsArrayExpression,
+    JsCallArguments, JsCallExpression, JsImport, JsObjectExpression, JsSyntaxToken, T,
 };
-use biome_rowan::AstNode;
+use biome_rowan::{AstNode, TriviaPieceKind};
+use clap::ValueEnum;
+use serde_json::Value;
 
-use crate::RuleRegistry;
+use crate::assist::{ASSIST_GROUP, AssistSettings};
+use crate::deprecated::stylistic_replacement;
+use crate::diagnostics::{Error, warning};
+use crate::hints::unmapped_hint;
+use crate::ignores::{collect_ignores, vcs_ignore_file};
+use crate::levels::{LevelCodec, LevelMapping, eslint_codec};
+use crate::options::{CssTarget, EjectOptions, GitignoreMode, SourceSelection};
+use crate::output::{apply_newline, resolve_newline};
+use crate::overrides::{Override, collect_overrides};
+use crate::presets::{ESLINT_RECOMMENDED, TYPESCRIPT_ESLINT_RULES};
+use crate::registry::{RuleInfo, RuleOrigin, RuleRegistry};
+use crate::rule_options::translate_rule_options;
+use crate::stylelint::{collect_stylelint_rules, eslint_css_equivalent};
 
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
-enum RuleSourceKind {
+pub enum RuleSourceKind {
     Clippy,
     DenoLint,
     Eslint,
@@ -103,66 +117,546 @@ impl From<&RuleSource> for RuleSourceKind {
 }
 
 impl RuleSourceKind {
-    pub fn as_namespace(&self) -> Option<&'static str> {
+    const ALL: [Self; 36] = [
+        Self::Clippy,
+        Self::DenoLint,
+        Self::Eslint,
+        Self::EslintBarrelFiles,
+        Self::EslintGraphql,
+        Self::EslintImport,
+        Self::EslintImportAccess,
+        Self::EslintJest,
+        Self::EslintJsDoc,
+        Self::EslintJsxA11y,
+        Self::EslintMysticatea,
+        Self::EslintN,
+        Self::EslintNext,
+        Self::EslintNoSecrets,
+        Self::EslintPackageJson,
+        Self::EslintPackageJsonDependencies,
+        Self::EslintPerfectionist,
+        Self::EslintQwik,
+        Self::EslintReact,
+        Self::EslintReactHooks,
+        Self::EslintReactPreferFunctionComponent,
+        Self::EslintReactRefresh,
+        Self::EslintReactX,
+        Self::EslintReactXyz,
+        Self::EslintRegexp,
+        Self::EslintSolid,
+        Self::EslintSonarJs,
+        Self::EslintStylistic,
+        Self::EslintTypeScript,
+        Self::EslintUnicorn,
+        Self::EslintUnusedImports,
+        Self::EslintVitest,
+        Self::EslintVueJs,
+        Self::GraphqlSchemaLinter,
+        Self::Stylelint,
+        Self::EslintTurbo,
+    ];
+
+    /// Finds the source of the namespaced rule name, falling back to the built-in ESLint rules.
+    pub fn from_namespaced_rule_name(rule_name: &str) -> Self {
+        Self::ALL
+            .into_iter()
+            .filter(|kind| {
+                kind.as_namespace().is_some_and(|namespace| {
+                    rule_name
+                        .strip_prefix(namespace)
+                        .is_some_and(|rule| rule.starts_with('/'))
+                })
+            })
+            .max_by_key(|kind| kind.as_namespace().map(str::len))
+            .unwrap_or(Self::Eslint)
+    }
+
+    /// Finds the source registered under the namespace.
+    pub fn from_namespace(namespace: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.as_namespace() == Some(namespace))
+    }
+
+    /// Returns the bare names of every rule of the plugin, if bundled.
+    fn known_rules(&self) -> Option<&'static [&'static str]> {
+        self.plugin_info()?.known_rules
+    }
+
+    /// Returns the metadata of the plugin providing the rules, even if not imported yet.
+    ///
+    /// Everything about a plugin lives in its row, so supporting another plugin takes only a row
+    /// here besides the variant itself, which is also listed in [`Self::ALL`] and converted from
+    /// [`RuleSource`].
+    fn plugin_info(&self) -> Option<PluginInfo> {
         Some(match self {
-            Self::EslintBarrelFiles => "barrel-files",
-            Self::EslintGraphql => "@graphql-eslint",
-            Self::EslintImport => "import",
-            Self::EslintImportAccess => "import-access",
-            Self::EslintJest => "jest",
-            Self::EslintJsDoc => "jsdoc",
-            Self::EslintJsxA11y => "jsx-a11y",
-            Self::EslintMysticatea => "@mysticatea",
-            Self::EslintN => "n",
-            Self::EslintNext => "@next/next",
-            Self::EslintNoSecrets => "no-secrets",
-            Self::EslintPackageJson => "package-json",
-            Self::EslintPackageJsonDependencies => "package-json-dependencies",
-            Self::EslintPerfectionist => "perfectionist",
-            Self::EslintQwik => "qwik",
-            Self::EslintReact => "react",
-            Self::EslintReactHooks => "react-hooks",
-            Self::EslintReactPreferFunctionComponent => "react-prefer-function-component",
-            Self::EslintReactRefresh => "react-refresh",
-            Self::EslintReactX => "react-x",
-            Self::EslintReactXyz => "@eslint-react",
-            Self::EslintRegexp => "regexp",
-            Self::EslintSolid => "solid",
-            Self::EslintSonarJs => "sonarjs",
-            Self::EslintStylistic => "@stylistic",
-            Self::EslintTurbo => "turbo",
-            Self::EslintTypeScript => "@typescript-eslint",
-            Self::EslintUnicorn => "unicorn",
-            Self::EslintUnusedImports => "unused-imports",
-            Self::EslintVitest => "vitest",
-            Self::EslintVueJs => "vue",
+            Self::EslintBarrelFiles => {
+                PluginInfo::unimported("eslint-plugin-barrel-files", "barrel-files")
+            }
+            Self::EslintGraphql => {
+                PluginInfo::unimported("@graphql-eslint/eslint-plugin", "@graphql-eslint")
+            }
+            Self::EslintImport => PluginInfo::new("importPlugin", "eslint-plugin-import", "import")
+                .legacy()
+                .docs(
+                    "https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/{rule}.md",
+                ),
+            Self::EslintImportAccess => {
+                PluginInfo::unimported("eslint-plugin-import-access", "import-access")
+            }
+            Self::EslintJest => PluginInfo::new("jest", "eslint-plugin-jest", "jest").docs(
+                "https://github.com/jest-community/eslint-plugin-jest/blob/main/docs/rules/{rule}.md",
+            ),
+            Self::EslintJsDoc => PluginInfo::unimported("eslint-plugin-jsdoc", "jsdoc"),
+            Self::EslintJsxA11y => PluginInfo::new("jsxA11y", "eslint-plugin-jsx-a11y", "jsx-a11y")
+                .docs(
+                    "https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/docs/rules/{rule}.md",
+                )
+                .scoped(ExtensionScope::Jsx),
+            Self::EslintMysticatea => {
+                PluginInfo::unimported("@mysticatea/eslint-plugin", "@mysticatea")
+            }
+            Self::EslintN => PluginInfo::new("n", "eslint-plugin-n", "n").docs(
+                "https://github.com/eslint-community/eslint-plugin-n/blob/master/docs/rules/{rule}.md",
+            ),
+            Self::EslintNext => PluginInfo::unimported("@next/eslint-plugin-next", "@next/next")
+                .legacy()
+                .docs("https://nextjs.org/docs/messages/{rule}"),
+            Self::EslintNoSecrets => {
+                PluginInfo::unimported("eslint-plugin-no-secrets", "no-secrets")
+            }
+            Self::EslintPackageJson => {
+                PluginInfo::unimported("eslint-plugin-package-json", "package-json")
+            }
+            Self::EslintPackageJsonDependencies => PluginInfo::unimported(
+                "eslint-plugin-package-json-dependencies",
+                "package-json-dependencies",
+            ),
+            Self::EslintPerfectionist => PluginInfo::new(
+                "perfectionist",
+                "eslint-plugin-perfectionist",
+                "perfectionist",
+            )
+            .docs("https://perfectionist.dev/rules/{rule}"),
+            Self::EslintQwik => PluginInfo::unimported("eslint-plugin-qwik", "qwik"),
+            Self::EslintReact => PluginInfo::new("react", "eslint-plugin-react", "react")
+                .docs(
+                    "https://github.com/jsx-eslint/eslint-plugin-react/blob/master/docs/rules/{rule}.md",
+                )
+                .scoped(ExtensionScope::Jsx),
+            Self::EslintReactHooks => {
+                PluginInfo::unimported("eslint-plugin-react-hooks", "react-hooks").legacy()
+            }
+            Self::EslintReactPreferFunctionComponent => PluginInfo::unimported(
+                "eslint-plugin-react-prefer-function-component",
+                "react-prefer-function-component",
+            ),
+            Self::EslintReactRefresh => {
+                PluginInfo::unimported("eslint-plugin-react-refresh", "react-refresh")
+            }
+            Self::EslintReactX => PluginInfo::new("reactX", "eslint-plugin-react-x", "react-x")
+                .scoped(ExtensionScope::Jsx),
+            // Rules of the sub-plugins (e.g. `@eslint-react/dom/...`) are also exposed from the
+            // umbrella plugin, so registering it under `@eslint-react` resolves all of them.
+            Self::EslintReactXyz => PluginInfo::new(
+                "eslintReact",
+                "@eslint-react/eslint-plugin",
+                "@eslint-react",
+            )
+            .scoped(ExtensionScope::Jsx),
+            // Exported as the module itself, e.g. `import * as regexp from "eslint-plugin-regexp"`
+            Self::EslintRegexp => PluginInfo::new("regexp", "eslint-plugin-regexp", "regexp")
+                .namespace_export()
+                .docs("https://ota-meshi.github.io/eslint-plugin-regexp/rules/{rule}.html"),
+            Self::EslintSolid => PluginInfo::unimported("eslint-plugin-solid", "solid"),
+            Self::EslintSonarJs => PluginInfo::new("sonarjs", "eslint-plugin-sonarjs", "sonarjs"),
+            Self::EslintStylistic => {
+                PluginInfo::new("stylistic", "@stylistic/eslint-plugin", "@stylistic")
+                    .docs("https://eslint.style/rules/{rule}")
+            }
+            Self::EslintTurbo => PluginInfo::unimported("eslint-plugin-turbo", "turbo"),
+            Self::EslintTypeScript => {
+                PluginInfo::new("tseslint", "typescript-eslint", "@typescript-eslint")
+                    .docs("https://typescript-eslint.io/rules/{rule}")
+                    .scoped(ExtensionScope::TypeScript)
+                    .known_rules(TYPESCRIPT_ESLINT_RULES)
+            }
+            Self::EslintUnicorn => {
+                PluginInfo::new("eslintPluginUnicorn", "eslint-plugin-unicorn", "unicorn").docs(
+                    "https://github.com/sindresorhus/eslint-plugin-unicorn/blob/main/docs/rules/{rule}.md",
+                )
+            }
+            Self::EslintUnusedImports => {
+                PluginInfo::unimported("eslint-plugin-unused-imports", "unused-imports")
+            }
+            Self::EslintVitest => PluginInfo::new("vitest", "@vitest/eslint-plugin", "vitest")
+                .docs(
+                    "https://github.com/vitest-dev/eslint-plugin-vitest/blob/main/docs/rules/{rule}.md",
+                ),
+            Self::EslintVueJs => PluginInfo::new("vue", "eslint-plugin-vue", "vue")
+                .docs("https://eslint.vuejs.org/rules/{rule}.html")
+                .scoped(ExtensionScope::Vue),
             _ => return None,
         })
     }
 
+    /// Returns the metadata of the plugin to import and register, if supported.
+    fn imported_plugin_info(&self) -> Option<PluginInfo> {
+        self.plugin_info().filter(|plugin| plugin.ident.is_some())
+    }
+
+    pub fn as_namespace(&self) -> Option<&'static str> {
+        Some(self.plugin_info()?.namespace)
+    }
+
+    /// Prefixes the bare rule name with the namespace, if any.
+    ///
+    /// The prefix is taken from [`Self::as_namespace`] rather than the table of Biome, so the rule
+    /// names always agree with the keys the plugins are registered under. A name already prefixed
+    /// is returned as-is, never to be prefixed twice (e.g. `unicorn/unicorn/...`).
+    pub fn to_namespaced_rule_name(&self, rule: &str) -> String {
+        let rule = self.to_bare_rule_name(rule);
+
+        match self.as_namespace() {
+            Some(namespace) => format!("{namespace}/{rule}"),
+            None => rule.to_string(),
+        }
+    }
+
+    /// Strips the namespace from the rule name, if prefixed.
+    pub fn to_bare_rule_name<'a>(&self, rule_name: &'a str) -> &'a str {
+        self.as_namespace()
+            .and_then(|namespace| rule_name.strip_prefix(namespace))
+            .and_then(|rule_name| rule_name.strip_prefix('/'))
+            .unwrap_or(rule_name)
+    }
+
+    /// Returns the URL to the documentation of the rule, given its bare name.
+    pub fn to_docs_url(&self, rule: &str) -> Option<String> {
+        match self {
+            Self::Eslint => Some(format!("https://eslint.org/docs/latest/rules/{rule}")),
+            _ => Some(self.plugin_info()?.docs_url?.replace("{rule}", rule)),
+        }
+    }
+
+    /// Returns the identifier to import the plugin as.
+    ///
+    /// The plugin is registered under [`Self::as_namespace`] rather than the identifier, so the
+    /// key always lines up with the prefix of the rule names, e.g. `sonarjs/cognitive-complexity`.
     fn to_ident(&self) -> Option<JsSyntaxToken> {
-        Some(match self {
-            Self::EslintTypeScript => make::ident("tseslint"),
-            _ => return None, // TODO: Support other many sources
-        })
+        Some(make::ident(self.imported_plugin_info()?.ident?))
+    }
+
+    /// Whether the rules can be loaded by ESLint, as built-in or of a plugin imported.
+    fn is_resolvable(&self) -> bool {
+        *self == Self::Eslint || self.imported_plugin_info().is_some()
+    }
+
+    /// Returns the npm package providing the rules, even if the plugin is not imported yet, which is
+    /// also the module to import the plugin from.
+    ///
+    /// This differs from the namespace for the most plugins, e.g. `@stylistic/eslint-plugin` for
+    /// `@stylistic`, and is `None` for the sources not on ESLint.
+    pub fn package_name(&self) -> Option<&'static str> {
+        match self {
+            Self::Eslint => Some("eslint"),
+            _ => Some(self.plugin_info()?.package),
+        }
+    }
+
+    /// Whether the plugin is written for the legacy eslintrc only, and needs `fixupPluginRules()` of
+    /// `@eslint/compat` to run in flat config.
+    pub fn is_legacy(&self) -> bool {
+        self.plugin_info().is_some_and(|plugin| plugin.legacy)
     }
 
     fn to_import(&self, ident: JsSyntaxToken) -> Option<JsImport> {
-        Some(match self {
-            Self::EslintTypeScript => make::js_import(
-                make::token_with_trailing_space(T![import]),
-                make::js_import_default_clause(
-                    make::js_default_import_specifier(make::js_identifier_binding(ident).into()),
-                    make::token_decorated_with_space(T![from]),
-                    make::js_module_source(make::js_string_literal("typescript-eslint")).into(),
-                )
-                .build()
-                .into(),
-            )
-            .build(),
-            _ => return None, // TODO: Support other many sources
+        let plugin = self.imported_plugin_info()?;
+
+        Some(if plugin.default_export {
+            default_import(ident, plugin.package)
+        } else {
+            namespace_import(ident, plugin.package)
         })
     }
+
+    /// Returns the files the rules of the source are limited to, when scoped by the extensions.
+    fn to_extension_scope(&self) -> Option<ExtensionScope> {
+        self.plugin_info()?.extension_scope
+    }
+}
+
+/// What to know about a plugin to import and register it in the flat config.
+#[derive(Clone, Copy, Debug)]
+struct PluginInfo {
+    /// Identifier to import the plugin as, e.g. `tseslint`, or `None` if not imported yet.
+    ident: Option<&'static str>,
+
+    /// Module to import the plugin from, which is also the name of its package.
+    package: &'static str,
+
+    /// Key to register the plugin under, which is also the prefix of its rule names.
+    namespace: &'static str,
+
+    /// Whether the plugin is the default export of the module, or the module itself otherwise.
+    default_export: bool,
+
+    /// Whether the plugin is written for the legacy eslintrc only.
+    legacy: bool,
+
+    /// URL to the documentation of the rules, with `{rule}` replaced by the bare rule name.
+    docs_url: Option<&'static str>,
+
+    /// Files the rules are limited to, when scoped by the extensions.
+    extension_scope: Option<ExtensionScope>,
+
+    /// Bare names of every rule of the plugin, if bundled.
+    known_rules: Option<&'static [&'static str]>,
+}
+
+impl PluginInfo {
+    const fn new(ident: &'static str, package: &'static str, namespace: &'static str) -> Self {
+        Self {
+            ident: Some(ident),
+            package,
+            namespace,
+            default_export: true,
+            legacy: false,
+            docs_url: None,
+            extension_scope: None,
+            known_rules: None,
+        }
+    }
+
+    /// Plugin not imported yet, whose namespace still names its rules.
+    const fn unimported(package: &'static str, namespace: &'static str) -> Self {
+        Self {
+            ident: None,
+            ..Self::new("", package, namespace)
+        }
+    }
+
+    /// Imports the module itself as the plugin, e.g. `import * as regexp from "..."`.
+    const fn namespace_export(self) -> Self {
+        Self {
+            default_export: false,
+            ..self
+        }
+    }
+
+    const fn legacy(self) -> Self {
+        Self {
+            legacy: true,
+            ..self
+        }
+    }
+
+    const fn docs(self, docs_url: &'static str) -> Self {
+        Self {
+            docs_url: Some(docs_url),
+            ..self
+        }
+    }
+
+    const fn scoped(self, extension_scope: ExtensionScope) -> Self {
+        Self {
+            extension_scope: Some(extension_scope),
+            ..self
+        }
+    }
+
+    const fn known_rules(self, known_rules: &'static [&'static str]) -> Self {
+        Self {
+            known_rules: Some(known_rules),
+            ..self
+        }
+    }
+}
+
+/// Set of the extensions to scope the rules of the language-specific plugins into.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum ExtensionScope {
+    TypeScript,
+    Jsx,
+    Vue,
+}
+
+impl ExtensionScope {
+    fn files(&self) -> &'static [&'static str] {
+        match self {
+            Self::TypeScript => &["**/*.{ts,tsx,mts,cts}"],
+            Self::Jsx => &["**/*.{jsx,tsx}"],
+            Self::Vue => &["**/*.vue"],
+        }
+    }
+
+    /// Whether the rules are scoped even without `--scope-by-extension`, as the files cannot be
+    /// parsed without the dedicated parser.
+    fn is_required(&self) -> bool {
+        matches!(self, Self::Vue)
+    }
+}
+
+// import ident from "module";
+fn default_import(ident: JsSyntaxToken, module: &str) -> JsImport {
+    make::js_import(
+        make::token_with_trailing_space(T![import]),
+        make::js_import_default_clause(
+            make::js_default_import_specifier(make::js_identifier_binding(ident).into()),
+            make::token_decorated_with_space(T![from]),
+            make::js_module_source(make::js_string_literal(module)).into(),
+        )
+        .build()
+        .into(),
+    )
+    .build()
+}
+
+// import * as ident from "module";
+fn namespace_import(ident: JsSyntaxToken, module: &str) -> JsImport {
+    make::js_import(
+        make::token_with_trailing_space(T![import]),
+        make::js_import_namespace_clause(
+            make::js_namespace_import_specifier(
+                make::token_with_trailing_space(T![*]),
+                make::token_with_trailing_space(T![as]),
+                make::js_identifier_binding(ident).into(),
+            ),
+            make::token_decorated_with_space(T![from]),
+            make::js_module_source(make::js_string_literal(module)).into(),
+        )
+        .build()
+        .into(),
+    )
+    .build()
+}
+
+/// Specifier to import the local file from the generated config, e.g. `./configs/base.mjs`.
+///
+/// Unlike the packages, the local files are resolved from the config only if prefixed by `./` or
+/// `../`, and always separated by slashes regardless of the platform.
+fn local_specifier(options: &EjectOptions, file: &Path) -> String {
+    let file = file.strip_prefix(&options.root).unwrap_or(file);
+    if file.is_absolute() {
+        return file.to_string_lossy().replace('\\', "/");
+    }
+
+    let segments = |path: &Path| -> Vec<String> {
+        path.components()
+            .filter(|component| *component != Component::CurDir)
+            .map(|component| component.as_os_str().to_string_lossy().into_owned())
+            .collect()
+    };
+
+    // Climbs up from the directory of the config written by `--output`, if not in the root
+    let from = segments(options.output.parent().unwrap_or(Path::new("")));
+    let to = segments(file);
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let specifier = std::iter::repeat_n("..".to_string(), from.len() - common)
+        .chain(to.into_iter().skip(common))
+        .collect::<Vec<_>>()
+        .join("/");
+
+    if specifier.starts_with("../") {
+        specifier
+    } else {
+        format!("./{specifier}")
+    }
+}
+
+// ...ident
+fn spread(ident: &str) -> AnyJsCallArgument {
+    AnyJsCallArgument::JsSpread(make::js_spread(
+        make::token(T![...]),
+        make::js_identifier_expression(make::js_reference_identifier(make::ident(ident))).into(),
+    ))
+}
+
+// import { name } from "module";
+fn named_import(name: &str, module: &str) -> JsImport {
+    make::js_import(
+        make::token_with_trailing_space(T![import]),
+        make::js_import_named_clause(
+            make::js_named_import_specifiers(
+                make::token_with_trailing_space(T!['{']),
+                make::js_named_import_specifier_list(
+                    [make::js_shorthand_named_import_specifier(
+                        make::js_identifier_binding(make::ident(name)).into(),
+                    )
+                    .build()
+                    .into()],
+                    [],
+                ),
+                make::token_with_leading_space(T!['}']),
+            ),
+            make::token_decorated_with_space(T![from]),
+            make::js_module_source(make::js_string_literal(module)).into(),
+        )
+        .build()
+        .into(),
+    )
+    .with_semicolon_token(make::token(T![;]))
+    .build()
+}
+
+// callee(arg, ...)
+fn call_expression(callee: &str, args: Vec<AnyJsCallArgument>) -> JsCallExpression {
+    make::js_call_expression(
+        make::js_identifier_expression(make::js_reference_identifier(make::ident(callee))).into(),
+        call_arguments(args),
+    )
+    .build()
+}
+
+// (arg, ...)
+fn call_arguments(args: Vec<AnyJsCallArgument>) -> JsCallArguments {
+    let arg_count = args.len();
+
+    make::js_call_arguments(
+        make::token(T!['(']),
+        make::js_call_argument_list(
+            args,
+            (1..arg_count).map(|_| make::token_with_trailing_space(T![,])),
+        ),
+        make::token(T![')']),
+    )
+}
+
+// includeIgnoreFile(fileURLToPath(new URL("./.gitignore", import.meta.url)))
+fn include_ignore_file(specifier: &str) -> JsCallExpression {
+    let import_meta_url = make::js_static_member_expression(
+        make::js_import_meta_expression(
+            make::token(T![import]),
+            make::token(T![.]),
+            make::token(T![meta]),
+        )
+        .into(),
+        make::token(T![.]),
+        make::js_name(make::ident("url")).into(),
+    );
+
+    let url = make::js_new_expression(
+        make::token_with_trailing_space(T![new]),
+        make::js_identifier_expression(make::js_reference_identifier(make::ident("URL"))).into(),
+    )
+    .with_arguments(call_arguments(vec![
+        AnyJsCallArgument::AnyJsExpression(AnyJsExpression::AnyJsLiteralExpression(
+            make::js_string_literal_expression(make::js_string_literal(specifier)).into(),
+        )),
+        AnyJsCallArgument::AnyJsExpression(import_meta_url.into()),
+    ]))
+    .build();
+
+    let path = call_expression(
+        "fileURLToPath",
+        vec![AnyJsCallArgument::AnyJsExpression(url.into())],
+    );
+
+    call_expression(
+        "includeIgnoreFile",
+        vec![AnyJsCallArgument::AnyJsExpression(path.into())],
+    )
 }
 
 fn group_config_to_severity(plain: &GroupPlainConfiguration) -> Option<Severity> {
@@ -195,7 +689,7 @@ fn severity_or_group_to_severity<G: RuleGroupExt>(
     }
 }
 
-fn get_configured_severity(
+pub fn get_configured_severity(
     config: &RulesConfiguration,
     group: &'static str,
     rule: &'static str,
@@ -230,192 +724,443 @@ fn get_configured_severity(
             .as_ref()
             .and_then(|group| severity_or_group_to_severity(group, rule)),
         "suspicious" => config
-            .performance
+            .suspicious
             .as_ref()
             .and_then(|group| severity_or_group_to_severity(group, rule)),
         _ => None,
     }
 }
 
-fn severity_to_eslint_level(severity: &Severity) -> &'static str {
-    match severity {
-        Severity::Error | Severity::Fatal => "error",
-        Severity::Warning | Severity::Information | Severity::Hint => "warn",
+/// Level of an ESLint rule.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum EslintLevel {
+    /// Turn the rule off.
+    Off,
+
+    /// Report the diagnostics as warnings.
+    Warn,
+
+    /// Report the diagnostics as errors.
+    Error,
+}
+
+impl EslintLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::Warn => "warn",
+            Self::Error => "error",
+        }
+    }
+}
+
+impl From<Severity> for EslintLevel {
+    fn from(value: Severity) -> Self {
+        match value {
+            Severity::Error | Severity::Fatal => Self::Error,
+            Severity::Warning | Severity::Information | Severity::Hint => Self::Warn,
+        }
     }
 }
 
-pub(crate) fn write_eslint_config(registry: &RuleRegistry, config: &Configuration) {
-    let rules_config = config.get_linter_rules();
-    let enabled_rules: BTreeSet<(&'static str, &'static str)> = rules_config
+/// Resolves the level of the rule, in the order of precedence:
+///
+/// 1. The severity configured for the rule, or for its group as a whole
+/// 2. The default severity in the metadata of the rule (e.g. an error for most of the correctness
+///    rules), if enabled by the presets such as `recommended`
+/// 3. `off`, if not enabled at all
+pub fn resolve_severity(
+    config: &RulesConfiguration,
+    enabled_rules: &BTreeSet<(&'static str, &'static str)>,
+    group: &'static str,
+    rule: &'static str,
+    info: &RuleInfo,
+    mapping: &LevelMapping,
+) -> EslintLevel {
+    if !enabled_rules.contains(&(group, rule)) {
+        return EslintLevel::Off;
+    }
+
+    mapping.level(get_configured_severity(config, group, rule).unwrap_or(info.severity))
+}
+
+/// Collects the rules enabled by the configuration.
+///
+/// The recommended rules are included unless `recommended: false`, in which case only the rules
+/// enabled explicitly (by themselves or by their group) are, with no recommended rule leaking in.
+///
+/// A group enabled as a whole is expanded into its rules in the registry, each at the severity of
+/// its metadata unless configured otherwise.
+pub fn enabled_rules(
+    registry: &RuleRegistry,
+    rules_config: &RulesConfiguration,
+) -> BTreeSet<(&'static str, &'static str)> {
+    rules_config
         .as_enabled_rules()
         .into_iter()
-        .filter_map(|filter| match filter {
-            RuleFilter::Group(_) => None,
-            RuleFilter::Rule(group, rule) => Some((group, rule)),
+        .flat_map(|filter| match filter {
+            RuleFilter::Group(group) => registry
+                .groups
+                .get(group)
+                .into_iter()
+                .chain(registry.css_groups.get(group))
+                .flat_map(|rules| rules.keys().map(|rule| (group, *rule)))
+                .collect(),
+            RuleFilter::Rule(group, rule) => vec![(group, rule)],
         })
-        .collect();
-
-    let mut sources = BTreeSet::<RuleSourceKind>::new();
-    let mut rules = BTreeMap::<String, Severity>::new();
+        .collect()
+}
 
-    for (group, registry_rules) in &registry.groups {
-        println!("{group}:");
+/// Collects the rules turned off by themselves (e.g. `"noDebugger": "off"`), as `group/rule`.
+///
+/// The rules turned off by their groups are left out, which would be too many to list.
+pub fn disabled_rules(rules_config: &RulesConfiguration) -> BTreeSet<String> {
+    let Ok(Value::Object(groups)) = serde_json::to_value(rules_config) else {
+        return BTreeSet::new();
+    };
 
-        for (rule, metadata) in registry_rules {
-            if !enabled_rules.contains(&(group, rule)) {
-                continue;
-            }
+    groups
+        .iter()
+        .filter_map(|(group, rules)| Some((group, rules.as_object()?)))
+        .flat_map(|(group, rules)| {
+            rules
+                .iter()
+                .filter(|(_, config)| {
+                    // "off", or { "level": "off", "options": { ... } }
+                    config.as_str().or_else(|| config.get("level")?.as_str()) == Some("off")
+                })
+                .map(move |(rule, _)| format!("{group}/{rule}"))
+        })
+        .collect()
+}
 
-            let severity =
-                get_configured_severity(&rules_config, group, rule).unwrap_or(metadata.severity);
+/// Collects the rules turned on without any severity (e.g. `"noDebugger": "on"`), as `group/rule`,
+/// or as `group` for the groups turned on as a whole (e.g. `"suspicious": "on"`).
+pub fn turned_on_rules(rules_config: &RulesConfiguration) -> BTreeSet<String> {
+    let Ok(Value::Object(groups)) = serde_json::to_value(rules_config) else {
+        return BTreeSet::new();
+    };
 
-            print!("  {rule}");
+    groups
+        .iter()
+        .flat_map(|(group, rules)| match rules {
+            Value::String(level) if level == "on" => vec![group.clone()],
+            Value::Object(rules) => rules
+                .iter()
+                .filter(|(_, config)| {
+                    // "on", or { "level": "on", "options": { ... } }
+                    config.as_str().or_else(|| config.get("level")?.as_str()) == Some("on")
+                })
+                .map(|(rule, _)| format!("{group}/{rule}"))
+                .collect(),
+            _ => Vec::new(),
+        })
+        .collect()
+}
 
-            let Some(rule_source) = metadata.sources.first() else {
-                println!(" -> None");
-                continue;
-            };
+/// A rule to emit into a `rules` object.
+#[derive(Clone)]
+pub struct RuleEntry {
+    pub level: EslintLevel,
 
-            let source_kind = RuleSourceKind::from(&rule_source.source);
-            let rule_name = rule_source.source.to_namespaced_rule_name();
+    /// Options of the rule, emitted after the level as `[level, ...options]`.
+    pub options: Vec<Value>,
 
-            println!(" -> {} ({})", &rule_name, severity);
+    /// Trailing comment to annotate the rule with, such as the URL to its documentation.
+    pub comment: Option<String>,
+}
 
-            sources.insert(source_kind);
-            rules.insert(rule_name, severity);
+impl RuleEntry {
+    fn new(level: EslintLevel) -> Self {
+        Self {
+            level,
+            options: Vec::new(),
+            comment: None,
         }
     }
+}
 
-    let mut imports = Vec::<JsImport>::new();
-    let mut plugins = Vec::<AnyJsObjectMember>::new();
-
-    for source in sources {
-        // Built-in, nothing to do
-        if source == RuleSourceKind::Eslint {
-            continue;
-        }
+fn with_trailing_comment(token: JsSyntaxToken, comment: &str) -> JsSyntaxToken {
+    token.with_trailing_trivia([
+        (TriviaPieceKind::Whitespace, " "),
+        (TriviaPieceKind::SingleLineComment, comment),
+    ])
+}
 
-        let Some(ident) = source.to_ident() else {
-            continue;
-        };
+// null, true, 1, "string", [...] or { ... }
+fn json_expression(value: &Value) -> AnyJsExpression {
+    match value {
+        Value::Null => AnyJsExpression::AnyJsLiteralExpression(
+            make::js_null_literal_expression(make::token(T![null])).into(),
+        ),
+        Value::Bool(value) => AnyJsExpression::AnyJsLiteralExpression(
+            make::js_boolean_literal_expression(if *value {
+                make::token(T![true])
+            } else {
+                make::token(T![false])
+            })
+            .into(),
+        ),
+        Value::Number(value) => AnyJsExpression::AnyJsLiteralExpression(
+            make::js_number_literal_expression(make::js_number_literal(value)).into(),
+        ),
+        Value::String(value) => AnyJsExpression::AnyJsLiteralExpression(
+            make::js_string_literal_expression(make::js_string_literal(value)).into(),
+        ),
+        Value::Array(values) => array_expression(values.iter().map(json_expression)).into(),
+        Value::Object(members) => {
+            let member_count = members.len();
 
-        if let Some(import) = source.to_import(ident.clone())
-            && let Some(namespace) = source.as_namespace()
-        {
-            imports.push(import);
-            plugins.push(
-                make::js_property_object_member(
-                    make::js_literal_member_name(make::js_string_literal(namespace)).into(),
-                    make::token_with_trailing_space(T![:]),
-                    make::js_identifier_expression(make::js_reference_identifier(ident)).into(),
-                )
-                .into(),
+            make::js_object_expression(
+                make::token(T!['{']),
+                make::js_object_member_list(
+                    members.iter().map(|(name, value)| {
+                        make::js_property_object_member(
+                            make::js_literal_member_name(make::js_string_literal(name)).into(),
+                            make::token_with_trailing_space(T![:]),
+                            json_expression(value),
+                        )
+                        .into()
+                    }),
+                    (1..member_count).map(|_| make::token_with_trailing_space(T![,])),
+                ),
+                make::token(T!['}']),
             )
+            .into()
         }
     }
+}
 
-    // { "@typescript-eslint": tseslint, ... }
-    let plugin_count = plugins.len();
-    let plugins = make::js_object_expression(
-        make::token(T!['{']),
-        make::js_object_member_list(
-            plugins,
-            (0..plugin_count - 1).map(|_| make::token_with_trailing_space(T![,])),
+// "error" or 2, as encoded by the codec
+fn level_expression(
+    level: &Value,
+    annotate: impl FnOnce(JsSyntaxToken) -> JsSyntaxToken,
+) -> AnyJsExpression {
+    match level {
+        Value::Number(level) => AnyJsExpression::AnyJsLiteralExpression(
+            make::js_number_literal_expression(annotate(make::js_number_literal(level))).into(),
         ),
-        make::token(T!['}']),
-    );
+        level => AnyJsExpression::AnyJsLiteralExpression(
+            make::js_string_literal_expression(annotate(make::js_string_literal(
+                level.as_str().unwrap_or_default(),
+            )))
+            .into(),
+        ),
+    }
+}
 
-    // { "no-octal": "error", ... }
-    let rules = make::js_object_expression(
+// { rule: "error", ... } or { rule: ["error", { ... }, ...], ... }
+fn rules_object(
+    rules: &BTreeMap<String, RuleEntry>,
+    array_levels: bool,
+    codec: &dyn LevelCodec,
+) -> JsObjectExpression {
+    let rule_count = rules.len();
+
+    make::js_object_expression(
         make::token(T!['{']),
         make::js_object_member_list(
-            rules.iter().map(|(name, severity)| {
+            rules.iter().map(|(name, entry)| {
+                let annotate = |token: JsSyntaxToken| match &entry.comment {
+                    Some(comment) => with_trailing_comment(token, &format!("// {comment}")),
+                    None => token,
+                };
+
+                let level = codec.encode(entry.level);
+                let value = if array_levels || !entry.options.is_empty() {
+                    let level = level_expression(&level, |token| token);
+                    let elements: Vec<_> = std::iter::once(level)
+                        .chain(entry.options.iter().map(json_expression))
+                        .map(AnyJsArrayElement::AnyJsExpression)
+                        .collect();
+                    let element_count = elements.len();
+
+                    // The comment goes after the bracket, not to be wrapped into the array
+                    make::js_array_expression(
+                        make::token(T!['[']),
+                        make::js_array_element_list(
+                            elements,
+                            (1..element_count).map(|_| make::token_with_trailing_space(T![,])),
+                        ),
+                        annotate(make::token(T![']'])),
+                    )
+                    .into()
+                } else {
+                    level_expression(&level, annotate)
+                };
+
                 make::js_property_object_member(
-                    make::js_literal_member_name(make::js_string_literal(name.as_str())).into(),
+                    make::js_literal_member_name(make::js_string_literal(name)).into(),
                     make::token_with_trailing_space(T![:]),
-                    AnyJsExpression::AnyJsLiteralExpression(
-                        make::js_string_literal_expression(make::js_string_literal(
-                            severity_to_eslint_level(severity),
-                        ))
-                        .into(),
-                    ),
+                    value,
                 )
                 .into()
             }),
-            (0..rules.len() - 1).map(|_| make::token_with_trailing_space(T![,])),
+            (1..rule_count).map(|_| make::token_with_trailing_space(T![,])),
         ),
         make::token(T!['}']),
-    );
+    )
+}
 
-    // import { defineConfig } from "eslint/config";
-    imports.push(
-        make::js_import(
-            make::token_with_trailing_space(T![import]),
-            make::js_import_named_clause(
-                make::js_named_import_specifiers(
-                    make::token_with_trailing_space(T!['{']),
-                    make::js_named_import_specifier_list(
-                        [make::js_shorthand_named_import_specifier(
-                            make::js_identifier_binding(make::ident("defineConfig")).into(),
-                        )
-                        .build()
-                        .into()],
-                        [],
-                    ),
-                    make::token_with_leading_space(T!['}']),
-                ),
-                make::token_decorated_with_space(T![from]),
-                make::js_module_source(make::js_string_literal("eslint/config")).into(),
-            )
-            .build()
-            .into(),
+// [element, ...]
+fn array_expression(elements: impl ExactSizeIterator<Item = AnyJsExpression>) -> JsArrayExpression {
+    let element_count = elements.len();
+
+    make::js_array_expression(
+        make::token(T!['[']),
+        make::js_array_element_list(
+            elements.map(AnyJsArrayElement::AnyJsExpression),
+            (1..element_count).map(|_| make::token_with_trailing_space(T![,])),
+        ),
+        make::token(T![']']),
+    )
+}
+
+// ["a", "b", ...]
+fn string_array(items: &[&str]) -> AnyJsExpression {
+    array_expression(items.iter().map(|item| {
+        AnyJsExpression::AnyJsLiteralExpression(
+            make::js_string_literal_expression(make::js_string_literal(item)).into(),
         )
-        .with_semicolon_token(make::token(T![;]))
-        .build(),
-    );
+    }))
+    .into()
+}
 
-    // { plugins: ..., rules: ... }
-    let config = make::js_object_expression(
+// { key: value, ... }
+//
+// The separators are counted from 1, so none is emitted for zero or one member, and never a
+// trailing one for more.
+fn config_object(members: Vec<(&str, AnyJsExpression)>) -> JsObjectExpression {
+    let member_count = members.len();
+
+    make::js_object_expression(
         make::token(T!['{']),
         make::js_object_member_list(
-            [
-                make::js_property_object_member(
-                    make::js_literal_member_name(make::ident("plugins")).into(),
-                    make::token_with_trailing_space(T![:]),
-                    plugins.into(),
-                )
-                .into(),
+            members.into_iter().map(|(name, value)| {
                 make::js_property_object_member(
-                    make::js_literal_member_name(make::ident("rules")).into(),
+                    make::js_literal_member_name(make::ident(name)).into(),
                     make::token_with_trailing_space(T![:]),
-                    rules.into(),
+                    value,
                 )
-                .into(),
-            ],
-            [make::token_with_trailing_space(T![,])],
+                .into()
+            }),
+            (1..member_count).map(|_| make::token_with_trailing_space(T![,])),
         ),
         make::token(T!['}']),
-    );
+    )
+}
 
-    // defineConfig(...)
-    let config = make::js_call_expression(
-        make::js_identifier_expression(make::js_reference_identifier(make::ident("defineConfig")))
-            .into(),
-        make::js_call_arguments(
-            make::token(T!['(']),
-            make::js_call_argument_list([AnyJsCallArgument::AnyJsExpression(config.into())], []),
-            make::token(T![')']),
+// { "import/resolver": { typescript: true } }
+fn import_resolver_settings(resolver: &str) -> JsObjectExpression {
+    let resolver = make::js_property_object_member(
+        make::js_literal_member_name(make::ident(resolver)).into(),
+        make::token_with_trailing_space(T![:]),
+        AnyJsExpression::AnyJsLiteralExpression(
+            make::js_boolean_literal_expression(make::token(T![true])).into(),
         ),
+    );
+
+    make::js_object_expression(
+        make::token(T!['{']),
+        make::js_object_member_list(
+            [make::js_property_object_member(
+                make::js_literal_member_name(make::js_string_literal("import/resolver")).into(),
+                make::token_with_trailing_space(T![:]),
+                make::js_object_expression(
+                    make::token(T!['{']),
+                    make::js_object_member_list([resolver.into()], []),
+                    make::token(T!['}']),
+                )
+                .into(),
+            )
+            .into()],
+            [],
+        ),
+        make::token(T!['}']),
     )
-    .build();
+}
+
+// { files: ["**/*.css"], language: "css/css", plugins: { css: css }, rules: { ... } }
+fn css_config_object(
+    rules: &BTreeMap<String, RuleEntry>,
+    array_levels: bool,
+    codec: &dyn LevelCodec,
+) -> JsObjectExpression {
+    let plugins = make::js_object_expression(
+        make::token(T!['{']),
+        make::js_object_member_list(
+            [make::js_property_object_member(
+                make::js_literal_member_name(make::js_string_literal("css")).into(),
+                make::token_with_trailing_space(T![:]),
+                make::js_identifier_expression(make::js_reference_identifier(make::ident("css")))
+                    .into(),
+            )
+            .into()],
+            [],
+        ),
+        make::token(T!['}']),
+    );
+
+    let language = AnyJsExpression::AnyJsLiteralExpression(
+        make::js_string_literal_expression(make::js_string_literal("css/css")).into(),
+    );
+
+    config_object(vec![
+        ("files", string_array(&["**/*.css"])),
+        ("language", language),
+        ("plugins", plugins.into()),
+        ("rules", rules_object(rules, array_levels, codec).into()),
+    ])
+}
+
+// { files: ["src/**"], ignores: ["**/*.test.ts"], rules: { ... } }
+fn override_config_object(
+    override_: &Override,
+    rules: &BTreeMap<String, RuleEntry>,
+    array_levels: bool,
+    codec: &dyn LevelCodec,
+) -> JsObjectExpression {
+    let mut members = Vec::new();
+
+    // An override without `includes` applies to every file, the same as a config without `files`
+    if !override_.files.is_empty() {
+        let files: Vec<_> = override_.files.iter().map(String::as_str).collect();
+        members.push(("files", string_array(&files)));
+    }
+
+    if !override_.ignores.is_empty() {
+        let ignores: Vec<_> = override_.ignores.iter().map(String::as_str).collect();
+        members.push(("ignores", string_array(&ignores)));
+    }
+
+    members.push(("rules", rules_object(rules, array_levels, codec).into()));
+
+    config_object(members)
+}
+
+/// Revision of Biome pinned in `Cargo.toml`, whose crates are not versioned on their own.
+const BIOME_REVISION: &str = "957cd8e83cf543db300dba9fc85e52a3ef6226b5";
 
-    // export default ...
+/// Comment at the top of the config, recording the Biome revision the rules are derived from.
+///
+/// The rules available differ by the version of Biome, so it helps to debug the mismatches after
+/// upgrading Biome.
+fn banner(newline: &str) -> String {
+    format!(
+        "// Generated by biome-eject {}{newline}// biome revision: {BIOME_REVISION}{newline}{newline}",
+        env!("CARGO_PKG_VERSION"),
+    )
+}
+
+// import ...;
+// export default ...;
+fn format_module(imports: Vec<JsImport>, export: AnyJsExpression, newline: &str) -> String {
     let export = make::js_export(
         make::js_decorator_list([]),
         make::token_with_trailing_space(T![export]),
         make::js_export_default_expression_clause(
             make::token_with_trailing_space(T![default]),
-            config.into(),
+            export,
         )
         .build()
         .into(),
@@ -438,8 +1183,2353 @@ pub(crate) fn write_eslint_config(registry: &RuleRegistry, config: &Configuratio
     let formatted = biome_js_formatter::format_node(options, root.syntax()).unwrap();
     let printed = formatted.print().unwrap();
 
-    File::create("eslint.config.mjs")
-        .unwrap()
-        .write_all(printed.as_code().as_bytes())
-        .unwrap();
+    apply_newline(printed.as_code(), newline)
+}
+
+/// The generated ESLint config, as the contents of the modules to write.
+pub struct EslintConfig {
+    /// `eslint.config.mjs`
+    pub config: String,
+
+    /// `eslint.overrides.mjs`, if the overrides are split from the config.
+    pub overrides: Option<String>,
+
+    /// Packages imported from the modules.
+    pub packages: BTreeSet<&'static str>,
+
+    /// ESLint rules configured for all the files, along with their levels.
+    pub rules: BTreeMap<String, EslintLevel>,
+
+    /// Enabled rules ejected into ESLint rules, as `group/rule`.
+    pub mapped: BTreeSet<String>,
+
+    /// Enabled rules without any ESLint equivalent, as `group/rule`.
+    pub unmapped: BTreeSet<String>,
+
+    /// Enabled rules at a severity ESLint cannot represent, as `group/rule`.
+    pub lossy: BTreeSet<String>,
+
+    /// Enabled rules omitted as their plugins are not supported yet, as `group/rule`.
+    pub unresolved: BTreeSet<String>,
+}
+
+/// Prints how the Biome rule is resolved to stderr, only in the verbose mode.
+fn log_resolution(options: &EjectOptions, rule: &str, resolution: impl std::fmt::Display) {
+    if options.verbose {
+        eprintln!("  {rule} -> {resolution}");
+    }
+}
+
+/// Resolves the ESLint rules to configure for the Biome rule, logging how they are resolved.
+///
+/// A Biome rule inspired by several ESLint rules (e.g. `no-unused-vars` and
+/// `@typescript-eslint/no-unused-vars`) is ejected into every one of them, each only once, unless
+/// narrowed down to one by [`SourceSelection`].
+fn resolve_rule(
+    rule: &str,
+    info: &RuleInfo,
+    level: EslintLevel,
+    options: &EjectOptions,
+) -> Vec<(RuleSourceKind, String, RuleEntry)> {
+    if info.sources.is_empty() {
+        if options.explain_unmapped {
+            match unmapped_hint(rule) {
+                Some(hint) => log_resolution(
+                    options,
+                    rule,
+                    format_args!("None (no direct equivalent; consider {hint})"),
+                ),
+                None => log_resolution(options, rule, "None (no direct equivalent)"),
+            }
+        } else {
+            log_resolution(options, rule, "None");
+        }
+        return Vec::new();
+    }
+
+    let level = match level {
+        EslintLevel::Warn if options.promote_warnings => EslintLevel::Error,
+        level => level,
+    };
+
+    let mut seen = BTreeSet::<String>::new();
+    let resolved: Vec<_> = info
+        .sources
+        .iter()
+        .filter_map(|rule_source| resolve_rule_source(rule, rule_source, level, options))
+        .filter(|(_, rule_name, _)| seen.insert(rule_name.clone()))
+        .collect();
+
+    let selected = match &options.source_selection {
+        SourceSelection::All => return resolved,
+        SourceSelection::First => 0,
+        SourceSelection::PreferNamespace(namespace) => resolved
+            .iter()
+            .position(|(source_kind, _, _)| {
+                source_kind.as_namespace().unwrap_or("eslint") == namespace
+            })
+            .unwrap_or(0),
+    };
+
+    resolved.into_iter().skip(selected).take(1).collect()
+}
+
+/// Whether any of the ESLint rules the Biome rule is inspired by is of a plugin not supported,
+/// which is skipped unless only the core rules are requested.
+fn has_unresolvable_source(info: &RuleInfo, options: &EjectOptions) -> bool {
+    !options.core_only
+        && info
+            .sources
+            .iter()
+            .any(|origin| !origin.kind.is_resolvable())
+}
+
+/// Resolves one of the ESLint rules the Biome rule is inspired by.
+fn resolve_rule_source(
+    rule: &str,
+    rule_source: &RuleOrigin,
+    level: EslintLevel,
+    options: &EjectOptions,
+) -> Option<(RuleSourceKind, String, RuleEntry)> {
+    let mut source_kind = rule_source.kind;
+    let mut rule_name = rule_source.name.clone();
+
+    if source_kind == RuleSourceKind::Eslint
+        && let Some(replacement) = stylistic_replacement(&rule_name)
+    {
+        if options.prefer_stylistic {
+            source_kind = RuleSourceKind::EslintStylistic;
+            rule_name = replacement;
+        } else {
+            warning!(
+                "{rule_name} is deprecated in ESLint, use {replacement} instead (or pass --prefer-stylistic)"
+            );
+        }
+    }
+
+    if options.core_only && source_kind != RuleSourceKind::Eslint {
+        log_resolution(
+            options,
+            rule,
+            format_args!("{rule_name} (skipped, not a core rule)"),
+        );
+        return None;
+    }
+
+    // Every namespaced rule has its plugin registered, not to fail loading the config
+    if !source_kind.is_resolvable() {
+        log_resolution(
+            options,
+            rule,
+            format_args!("{rule_name} (skipped, plugin not supported)"),
+        );
+        return None;
+    }
+
+    log_resolution(
+        options,
+        rule,
+        format_args!("{rule_name} ({})", level.as_str()),
+    );
+
+    // Core rules are configured as-is (e.g. `eqeqeq`, not `eslint/eqeqeq`), and the plugins
+    // object relies on it to skip registering the built-in source.
+    // Plugin rules are always namespaced, hence `--rule-name-style bare` is rejected: flat config
+    // resolves a bare name against the core rules only, even in a config object of a single plugin.
+    let mut entry = RuleEntry::new(level);
+    if options.include_metadata_comment {
+        entry.comment = source_kind.to_docs_url(source_kind.to_bare_rule_name(&rule_name));
+    }
+
+    Some((source_kind, rule_name, entry))
+}
+
+/// Collects the rules enabled by the configuration but missing in the registry, as `group/rule`.
+fn unknown_rules(
+    registry: &RuleRegistry,
+    enabled_rules: &BTreeSet<(&'static str, &'static str)>,
+) -> Vec<String> {
+    enabled_rules
+        .iter()
+        .filter(|(group, rule)| !registry.contains(group, rule))
+        .map(|(group, rule)| format!("{group}/{rule}"))
+        .collect()
+}
+
+/// Removes the rules restating the preset, which enables every rule in it at `error`, and turns
+/// off the rules of the preset not configured.
+///
+/// Returns the rules contradicting the preset along with their levels, which are kept as overrides.
+fn subtract_preset(
+    rules: &mut BTreeMap<String, RuleEntry>,
+    preset: &[&'static str],
+) -> Vec<(&'static str, EslintLevel)> {
+    let mut contradictions = Vec::new();
+
+    for &rule in preset {
+        match rules.get(rule) {
+            Some(entry) if entry.level == EslintLevel::Error && entry.options.is_empty() => {
+                rules.remove(rule);
+            }
+            // Kept as configured, but the preset is contradicted rather than restated
+            Some(entry) => contradictions.push((rule, entry.level)),
+            None => {
+                rules.insert(rule.to_string(), RuleEntry::new(EslintLevel::Off));
+            }
+        }
+    }
+
+    contradictions
+}
+
+/// Rules resolved from the top level of the configuration, before assembling into a config.
+#[derive(Clone)]
+pub struct BaseRules {
+    pub sources: BTreeSet<RuleSourceKind>,
+
+    /// Rules of the global config object.
+    pub rules: BTreeMap<String, RuleEntry>,
+
+    /// Rules of the groups configured in their dedicated config objects.
+    pub scoped_rules: BTreeMap<&'static str, BTreeMap<String, RuleEntry>>,
+
+    /// Enabled rules ejected into ESLint rules, as `group/rule`.
+    pub mapped: BTreeSet<String>,
+
+    /// Enabled rules without any ESLint equivalent, as `group/rule`.
+    pub unmapped: BTreeSet<String>,
+
+    /// Enabled rules at a severity ESLint cannot represent, as `group/rule`.
+    pub lossy: BTreeSet<String>,
+
+    /// Enabled rules omitted as their plugins are not supported yet, as `group/rule`.
+    pub unresolved: BTreeSet<String>,
+}
+
+pub fn collect_base_rules(
+    registry: &RuleRegistry,
+    config: &Configuration,
+    options: &EjectOptions,
+) -> BaseRules {
+    let rules_config = config.get_linter_rules();
+    let enabled_rules = enabled_rules(registry, &rules_config);
+    let disabled_rules = disabled_rules(&rules_config);
+    let assist = AssistSettings::new(config);
+
+    let mut sources = BTreeSet::<RuleSourceKind>::new();
+    let mut rules = BTreeMap::<String, RuleEntry>::new();
+    let mut scoped_rules = BTreeMap::<&'static str, BTreeMap<String, RuleEntry>>::new();
+    let mut mapped = BTreeSet::<String>::new();
+    let mut unmapped = BTreeSet::<String>::new();
+    let mut lossy = BTreeSet::<String>::new();
+    let mut unresolved = BTreeSet::<String>::new();
+
+    for (group, registry_rules) in &registry.groups {
+        if options.skip_nursery && *group == "nursery" {
+            continue;
+        }
+
+        if options.verbose {
+            eprintln!("{group}:");
+        }
+
+        for (rule, info) in registry_rules {
+            let level = if *group == ASSIST_GROUP {
+                match assist.get_configured_severity(rule, info) {
+                    Some(severity) => options.level_mapping.level(severity),
+                    None => continue,
+                }
+            } else {
+                resolve_severity(
+                    &rules_config,
+                    &enabled_rules,
+                    group,
+                    rule,
+                    info,
+                    &options.level_mapping,
+                )
+            };
+
+            // Turned off explicitly, kept as `off` not to lose the intent unless --skip-off
+            let disabled = level == EslintLevel::Off;
+            if disabled
+                && (options.skip_off || !disabled_rules.contains(&format!("{group}/{rule}")))
+            {
+                continue;
+            }
+
+            // Left to the base preset, which is assumed to cover the defaults of Biome
+            if options.only_deviations && *group != ASSIST_GROUP {
+                let default_level = if info.recommended {
+                    options.level_mapping.level(info.severity)
+                } else {
+                    EslintLevel::Off
+                };
+
+                if level == default_level {
+                    continue;
+                }
+            }
+
+            if !disabled && info.sources.is_empty() {
+                unmapped.insert(format!("{group}/{rule}"));
+            }
+
+            // Information and hints have no level of their own in ESLint, downgraded to warnings
+            if !disabled
+                && *group != ASSIST_GROUP
+                && matches!(
+                    get_configured_severity(&rules_config, group, rule).unwrap_or(info.severity),
+                    Severity::Information | Severity::Hint
+                )
+            {
+                lossy.insert(format!("{group}/{rule}"));
+            }
+
+            let resolved = resolve_rule(rule, info, level, options);
+            if !disabled && resolved.is_empty() && has_unresolvable_source(info, options) {
+                unresolved.insert(format!("{group}/{rule}"));
+            }
+
+            for (source_kind, rule_name, mut entry) in resolved {
+                entry.options = translate_rule_options(&rules_config, group, rule, &rule_name);
+                sources.insert(source_kind);
+
+                if !disabled {
+                    mapped.insert(format!("{group}/{rule}"));
+                }
+
+                let group_rules = if options.scopes.contains_key(*group) {
+                    scoped_rules.entry(*group).or_default()
+                } else {
+                    &mut rules
+                };
+
+                group_rules.insert(rule_name, entry);
+            }
+        }
+    }
+
+    // Renamed or removed upstream, which would silently be missing in the config otherwise
+    for rule in unknown_rules(registry, &enabled_rules) {
+        // Without the registry, only the rules in the mapping are known, and the rest are just
+        // not translated
+        if cfg!(feature = "registry") {
+            warning!("rule {rule} is enabled but unknown to this Biome version");
+        } else {
+            unmapped.insert(rule);
+        }
+    }
+
+    BaseRules {
+        sources,
+        rules,
+        scoped_rules,
+        mapped,
+        unmapped,
+        lossy,
+        unresolved,
+    }
+}
+
+/// Builds the flat config along with the modules accompanying it, returning the formatted code.
+///
+/// Nothing is written here, leaving it to [`crate::output::OutputFile`] so the result can be
+/// compared against the filesystem or printed instead.
+pub fn build_eslint_config(
+    registry: &RuleRegistry,
+    config: &Configuration,
+    options: &EjectOptions,
+) -> Result<EslintConfig, Error> {
+    let base_rules = collect_base_rules(registry, config, options);
+
+    build_eslint_config_from(registry, config, options, base_rules)
+}
+
+/// Builds the flat config from the rules resolved already, e.g. shared with the other targets.
+pub fn build_eslint_config_from(
+    registry: &RuleRegistry,
+    config: &Configuration,
+    options: &EjectOptions,
+    base_rules: BaseRules,
+) -> Result<EslintConfig, Error> {
+    let newline = resolve_newline(options.line_ending, config);
+    let codec = eslint_codec(options.numeric_levels);
+    let ignores = collect_ignores(config, &options.root, options.gitignore);
+    let ignore_file = match options.gitignore {
+        GitignoreMode::Inline => None,
+        GitignoreMode::Runtime => vcs_ignore_file(config, &options.root),
+    };
+    let enabled_rules = enabled_rules(registry, &config.get_linter_rules());
+
+    let BaseRules {
+        mut sources,
+        mut rules,
+        scoped_rules,
+        mapped,
+        unmapped,
+        lossy,
+        mut unresolved,
+    } = base_rules;
+
+    // Recorded before the rules are moved into the config objects, for the report
+    let converted: BTreeMap<_, _> = rules
+        .iter()
+        .chain(scoped_rules.values().flatten())
+        .map(|(name, entry)| (name.clone(), entry.level))
+        .collect();
+
+    // Turns off the rest of the plugins, not to be enabled by any shared config extended later
+    for namespace in &options.explicit_off_complement {
+        let Some((source, known_rules)) = RuleSourceKind::from_namespace(namespace)
+            .and_then(|source| Some((source, source.known_rules()?)))
+        else {
+            warning!("the rules of {namespace} are unknown, skipping the complement");
+            continue;
+        };
+
+        sources.insert(source);
+        for rule in known_rules {
+            rules
+                .entry(source.to_namespaced_rule_name(rule))
+                .or_insert_with(|| RuleEntry::new(EslintLevel::Off));
+        }
+    }
+
+    // Leaves the rules to the preset, overriding only the ones Biome configures differently
+    if options.extend_js_recommended {
+        for (rule, level) in subtract_preset(&mut rules, ESLINT_RECOMMENDED) {
+            warning!(
+                "{rule} is configured differently from js.configs.recommended, overriding it with {}",
+                level.as_str()
+            );
+        }
+    }
+
+    // The rules of the language-specific plugins are moved out of the global config object
+    let mut extension_rules = BTreeMap::<ExtensionScope, BTreeMap<String, RuleEntry>>::new();
+    for (name, entry) in std::mem::take(&mut rules) {
+        match RuleSourceKind::from_namespaced_rule_name(&name)
+            .to_extension_scope()
+            .filter(|scope| options.scope_by_extension || scope.is_required())
+        {
+            Some(scope) => extension_rules
+                .entry(scope)
+                .or_default()
+                .insert(name, entry),
+            None => rules.insert(name, entry),
+        };
+    }
+
+    let mut override_configs = Vec::<JsObjectExpression>::new();
+    for (index, override_) in collect_overrides(config).iter().enumerate() {
+        let override_enabled_rules = self::enabled_rules(registry, &override_.rules);
+        let override_disabled_rules = self::disabled_rules(&override_.rules);
+        let override_turned_on_rules = self::turned_on_rules(&override_.rules);
+        let mut override_rules = BTreeMap::<String, RuleEntry>::new();
+
+        if options.verbose {
+            eprintln!("overrides[{index}]:");
+        }
+
+        for (group, registry_rules) in &registry.groups {
+            if *group == ASSIST_GROUP || (options.skip_nursery && *group == "nursery") {
+                continue;
+            }
+
+            for (rule, info) in registry_rules {
+                // Shares the resolution with the top level, so a rule-level severity still takes
+                // precedence over the group-level one within the override
+                let level = match get_configured_severity(&override_.rules, group, rule) {
+                    Some(severity) => options.level_mapping.level(severity),
+                    None if !options.skip_off
+                        && override_disabled_rules.contains(&format!("{group}/{rule}")) =>
+                    {
+                        EslintLevel::Off
+                    }
+                    // Turned on without any severity, at the default of the rule as at the top level
+                    None if override_enabled_rules.contains(&(group, rule))
+                        && (override_turned_on_rules.contains(*group)
+                            || override_turned_on_rules.contains(&format!("{group}/{rule}"))) =>
+                    {
+                        options.level_mapping.level(info.severity)
+                    }
+                    None => continue,
+                };
+
+                if !enabled_rules.contains(&(group, rule))
+                    && !override_enabled_rules.contains(&(group, rule))
+                {
+                    continue;
+                }
+
+                let resolved = resolve_rule(rule, info, level, options);
+                if level != EslintLevel::Off
+                    && resolved.is_empty()
+                    && has_unresolvable_source(info, options)
+                {
+                    unresolved.insert(format!("{group}/{rule}"));
+                }
+
+                for (source_kind, rule_name, mut entry) in resolved {
+                    entry.options =
+                        translate_rule_options(&override_.rules, group, rule, &rule_name);
+                    // Only the plugin is shared with the base, so a rule enabled only in the
+                    // override never leaks into the global config object
+                    sources.insert(source_kind);
+                    override_rules.insert(rule_name, entry);
+                }
+            }
+        }
+
+        if !override_rules.is_empty() {
+            override_configs.push(override_config_object(
+                override_,
+                &override_rules,
+                options.uniform_array_levels,
+                codec,
+            ));
+        }
+    }
+
+    let mut imports = Vec::<JsImport>::new();
+
+    // `defineConfig` is imported from `eslint/config`
+    let mut packages = BTreeSet::from_iter(RuleSourceKind::Eslint.package_name());
+    let mut plugins = Vec::<AnyJsObjectMember>::new();
+    let mut namespaces = BTreeMap::<&str, RuleSourceKind>::new();
+
+    // The rules of eslint-plugin-import cannot resolve the modules without a resolver
+    let import_resolver = (!options.no_import_resolver
+        && sources.contains(&RuleSourceKind::EslintImport))
+    .then(|| {
+        if sources.contains(&RuleSourceKind::EslintTypeScript) {
+            "typescript"
+        } else {
+            "node"
+        }
+    });
+
+    for source in sources {
+        // Built-in, nothing to do as the core rules are not namespaced
+        if source == RuleSourceKind::Eslint {
+            continue;
+        }
+
+        let Some(ident) = source.to_ident() else {
+            continue;
+        };
+
+        if let Some(import) = source.to_import(ident.clone())
+            && let Some(namespace) = source.as_namespace()
+        {
+            // Registering another plugin under the same key would silently replace the former
+            if let Some(registered) = namespaces.insert(namespace, source) {
+                return Err(Error::new(
+                    None,
+                    format_args!(
+                        "both {registered:?} and {source:?} are registered under the namespace {namespace}"
+                    ),
+                ));
+            }
+
+            packages.extend(source.package_name());
+            imports.push(import);
+
+            let plugin: AnyJsExpression =
+                make::js_identifier_expression(make::js_reference_identifier(ident)).into();
+
+            // fixupPluginRules(plugin)
+            let plugin = if source.is_legacy() {
+                if packages.insert("@eslint/compat") {
+                    imports.push(named_import("fixupPluginRules", "@eslint/compat"));
+                }
+
+                call_expression(
+                    "fixupPluginRules",
+                    vec![AnyJsCallArgument::AnyJsExpression(plugin)],
+                )
+                .into()
+            } else {
+                plugin
+            };
+
+            plugins.push(
+                make::js_property_object_member(
+                    make::js_literal_member_name(make::js_string_literal(namespace)).into(),
+                    make::token_with_trailing_space(T![:]),
+                    plugin,
+                )
+                .into(),
+            )
+        }
+    }
+
+    // { "@typescript-eslint": tseslint, ... }, omitted if only the core rules are configured
+    let plugin_count = plugins.len();
+    let plugins = (plugin_count > 0).then(|| {
+        make::js_object_expression(
+            make::token(T!['{']),
+            make::js_object_member_list(
+                plugins,
+                (1..plugin_count).map(|_| make::token_with_trailing_space(T![,])),
+            ),
+            make::token(T!['}']),
+        )
+    });
+
+    // { "no-octal": "error", ... }
+    let rules = rules_object(&rules, options.uniform_array_levels, codec);
+
+    let mut css_config = None;
+    if options.css_target == Some(CssTarget::Eslint) {
+        let mut css_rules = BTreeMap::<String, RuleEntry>::new();
+
+        if options.verbose {
+            eprintln!("css:");
+        }
+
+        for (rule, severity) in collect_stylelint_rules(registry, config) {
+            match eslint_css_equivalent(&rule) {
+                Some(name) => {
+                    log_resolution(options, &rule, format_args!("css/{name} ({severity})"));
+                    let mut entry = RuleEntry::new(options.level_mapping.level(severity));
+                    if options.include_metadata_comment {
+                        entry.comment = Some(format!(
+                            "https://github.com/eslint/css/blob/main/docs/rules/{name}.md"
+                        ));
+                    }
+
+                    css_rules.insert(format!("css/{name}"), entry);
+                }
+                None => log_resolution(options, &rule, "None"),
+            }
+        }
+
+        if !css_rules.is_empty() {
+            packages.insert("@eslint/css");
+            imports.push(default_import(make::ident("css"), "@eslint/css"));
+            css_config = Some(css_config_object(
+                &css_rules,
+                options.uniform_array_levels,
+                codec,
+            ));
+        }
+    }
+
+    // import { defineConfig } from "eslint/config";
+    imports.push(named_import("defineConfig", "eslint/config"));
+
+    // { plugins: ..., rules: ... }
+    let mut members = Vec::<(&str, AnyJsExpression)>::new();
+    if let Some(plugins) = plugins {
+        members.push(("plugins", plugins.into()));
+    }
+    members.push(("rules", rules.into()));
+    if let Some(resolver) = import_resolver {
+        members.push(("settings", import_resolver_settings(resolver).into()));
+    }
+
+    let config = config_object(members);
+
+    // { ignores: ["dist", ...] }
+    let ignores: Vec<_> = ignores.iter().map(String::as_str).collect();
+    let ignores_config = (!ignores.is_empty()).then(|| -> AnyJsExpression {
+        if options.flat_config_helpers {
+            // globalIgnores(["dist", ...])
+            packages.insert("@eslint/config-helpers");
+            imports.push(named_import("globalIgnores", "@eslint/config-helpers"));
+            call_expression(
+                "globalIgnores",
+                vec![AnyJsCallArgument::AnyJsExpression(string_array(&ignores))],
+            )
+            .into()
+        } else {
+            config_object(vec![("ignores", string_array(&ignores))]).into()
+        }
+    });
+
+    // Read at runtime, not to go stale on the changes of the .gitignore
+    let gitignore_config = ignore_file.map(|path| -> AnyJsExpression {
+        packages.insert("@eslint/compat");
+        imports.push(named_import("fileURLToPath", "node:url"));
+        imports.push(named_import("includeIgnoreFile", "@eslint/compat"));
+
+        include_ignore_file(&local_specifier(options, &path)).into()
+    });
+
+    // Spreads the overrides from the separate module, or inlines them by default
+    let mut overrides_module = None;
+    let overrides = if options.split_overrides && !override_configs.is_empty() {
+        // export default [{ files: ..., rules: ... }, ...]
+        let overrides = array_expression(override_configs.into_iter().map(AnyJsExpression::from));
+        overrides_module = Some(format_module(Vec::new(), overrides.into(), newline));
+
+        imports.push(default_import(
+            make::ident("overrides"),
+            &local_specifier(
+                options,
+                &options.output.with_file_name("eslint.overrides.mjs"),
+            ),
+        ));
+
+        vec![spread("overrides")]
+    } else {
+        override_configs
+            .into_iter()
+            .map(|config| AnyJsCallArgument::AnyJsExpression(config.into()))
+            .collect()
+    };
+
+    // { files: ["**/*.{ts,tsx,mts,cts}"], languageOptions: { parser: tseslint.parser }, rules: { ... } }
+    let typescript_parser = namespaces.contains_key("@typescript-eslint");
+    if extension_rules.contains_key(&ExtensionScope::Vue) {
+        packages.insert("vue-eslint-parser");
+        imports.push(default_import(
+            make::ident("vueParser"),
+            "vue-eslint-parser",
+        ));
+    }
+
+    let extension_configs = extension_rules.iter().map(|(scope, rules)| {
+        let mut members = vec![("files", string_array(scope.files()))];
+        if *scope == ExtensionScope::Vue {
+            // { files: ["**/*.vue"], languageOptions: { parser: vueParser }, processor: "vue/vue" }
+            let parser = make::js_identifier_expression(make::js_reference_identifier(
+                make::ident("vueParser"),
+            ));
+
+            members.push((
+                "languageOptions",
+                config_object(vec![("parser", parser.into())]).into(),
+            ));
+            members.push((
+                "processor",
+                AnyJsExpression::AnyJsLiteralExpression(
+                    make::js_string_literal_expression(make::js_string_literal("vue/vue")).into(),
+                ),
+            ));
+        }
+
+        if *scope == ExtensionScope::TypeScript && typescript_parser {
+            let parser = make::js_static_member_expression(
+                make::js_identifier_expression(make::js_reference_identifier(make::ident(
+                    "tseslint",
+                )))
+                .into(),
+                make::token(T![.]),
+                make::js_name(make::ident("parser")).into(),
+            );
+
+            members.push((
+                "languageOptions",
+                config_object(vec![("parser", parser.into())]).into(),
+            ));
+        }
+
+        members.push((
+            "rules",
+            rules_object(rules, options.uniform_array_levels, codec).into(),
+        ));
+
+        AnyJsExpression::from(config_object(members))
+    });
+
+    // { name: "a11y", files: ["**/*.{jsx,tsx}"], rules: { ... } }
+    let scoped_configs = scoped_rules.iter().map(|(group, rules)| {
+        let scope = &options.scopes[*group];
+        let files: Vec<_> = scope.files.iter().map(String::as_str).collect();
+
+        let mut members = Vec::new();
+        if let Some(name) = &scope.name {
+            members.push((
+                "name",
+                AnyJsExpression::AnyJsLiteralExpression(
+                    make::js_string_literal_expression(make::js_string_literal(name)).into(),
+                ),
+            ));
+        }
+
+        if !files.is_empty() {
+            members.push(("files", string_array(&files)));
+        }
+        members.push((
+            "rules",
+            rules_object(rules, options.uniform_array_levels, codec).into(),
+        ));
+
+        let object = config_object(members);
+
+        // The rules in the nursery group may change or be removed in any release of Biome
+        let object = if *group == "nursery" {
+            let l_curly = object.l_curly_token().unwrap().with_leading_trivia([
+                (
+                    TriviaPieceKind::SingleLineComment,
+                    "// Experimental: ported from the nursery rules of Biome, which are not stable yet",
+                ),
+                (TriviaPieceKind::Newline, "\n"),
+            ]);
+
+            object.with_l_curly_token(l_curly)
+        } else {
+            object
+        };
+
+        AnyJsExpression::from(object)
+    });
+
+    // js.configs.recommended
+    let js_recommended = options.extend_js_recommended.then(|| -> AnyJsExpression {
+        packages.insert("@eslint/js");
+        imports.push(default_import(make::ident("js"), "@eslint/js"));
+
+        let js = make::js_identifier_expression(make::js_reference_identifier(make::ident("js")));
+        let configs = make::js_static_member_expression(
+            js.into(),
+            make::token(T![.]),
+            make::js_name(make::ident("configs")).into(),
+        );
+
+        make::js_static_member_expression(
+            configs.into(),
+            make::token(T![.]),
+            make::js_name(make::ident("recommended")).into(),
+        )
+        .into()
+    });
+
+    // ...base, extended by the generated config objects
+    let base_config = options.base_config.as_ref().map(|base_config| {
+        imports.push(default_import(
+            make::ident("base"),
+            &local_specifier(options, base_config),
+        ));
+
+        spread("base")
+    });
+
+    let configs: Vec<_> = base_config
+        .into_iter()
+        .chain(
+            ignores_config
+                .into_iter()
+                .chain(gitignore_config)
+                .chain(js_recommended)
+                .chain(std::iter::once(config.into()))
+                .chain(extension_configs)
+                .chain(scoped_configs)
+                .map(AnyJsCallArgument::AnyJsExpression),
+        )
+        .chain(overrides)
+        .chain(
+            css_config
+                .into_iter()
+                .map(|config| AnyJsCallArgument::AnyJsExpression(config.into())),
+        )
+        .collect();
+
+    // defineConfig(...)
+    let config = call_expression("defineConfig", configs);
+
+    let config = banner(newline) + &format_module(imports, config.into(), newline);
+
+    Ok(EslintConfig {
+        config,
+        overrides: overrides_module,
+        packages,
+        rules: converted,
+        mapped,
+        unmapped,
+        lossy,
+        unresolved,
+    })
+}
+
+/// Builds the ambient declaration of `eslint.config.mjs`, for the typed tools importing it.
+pub fn build_eslint_config_types(config: &Configuration, options: &EjectOptions) -> String {
+    let declaration = r#"import type { Linter } from "eslint";
+
+declare const config: Linter.Config[];
+export default config;
+"#;
+
+    apply_newline(declaration, resolve_newline(options.line_ending, config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{config, options, registry};
+
+    #[test]
+    fn namespaces_do_not_collide() {
+        let mut namespaces = BTreeMap::new();
+
+        for kind in RuleSourceKind::ALL {
+            if let Some(namespace) = kind.as_namespace()
+                && let Some(registered) = namespaces.insert(namespace, kind)
+            {
+                panic!("both {registered:?} and {kind:?} are under the namespace {namespace}");
+            }
+        }
+    }
+
+    #[test]
+    fn plugins_are_imported_as_in_the_table() {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["regexp/no-useless-flag"],
+            ),
+            (
+                "style",
+                "useImportType",
+                Severity::Error,
+                &["import/consistent-type-specifier-style"],
+            ),
+        ]);
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": {
+                        "suspicious": { "noDebugger": "error" },
+                        "style": { "useImportType": "error" }
+                    }
+                }
+            }"#,
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .config;
+        assert!(eslint_config.contains(r#"import * as regexp from "eslint-plugin-regexp";"#));
+        assert!(eslint_config.contains(r#"import importPlugin from "eslint-plugin-import";"#));
+        assert!(eslint_config.contains("fixupPluginRules(importPlugin)"));
+    }
+
+    #[test]
+    fn rows_of_the_table_describe_the_plugins() {
+        let kind = RuleSourceKind::EslintTypeScript;
+        assert_eq!(
+            kind.to_docs_url("no-explicit-any").as_deref(),
+            Some("https://typescript-eslint.io/rules/no-explicit-any")
+        );
+        assert_eq!(kind.to_extension_scope(), Some(ExtensionScope::TypeScript));
+        assert_eq!(kind.known_rules(), Some(TYPESCRIPT_ESLINT_RULES));
+
+        let kind = RuleSourceKind::EslintVueJs;
+        assert_eq!(kind.to_extension_scope(), Some(ExtensionScope::Vue));
+        assert_eq!(kind.known_rules(), None);
+
+        // Built into ESLint, without any row
+        assert_eq!(
+            RuleSourceKind::Eslint.to_docs_url("no-debugger").as_deref(),
+            Some("https://eslint.org/docs/latest/rules/no-debugger")
+        );
+        assert_eq!(RuleSourceKind::EslintSolid.to_docs_url("no-console"), None);
+    }
+
+    #[test]
+    fn packages_are_named_after_the_sources() {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["regexp/no-useless-flag"],
+            ),
+            (
+                "suspicious",
+                "noExplicitAny",
+                Severity::Error,
+                &["@typescript-eslint/no-explicit-any"],
+            ),
+            (
+                "correctness",
+                "noUnusedVariables",
+                Severity::Error,
+                &["react-hooks/exhaustive-deps"],
+            ),
+        ]);
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": {
+                        "suspicious": { "noDebugger": "error", "noExplicitAny": "error" },
+                        "correctness": { "noUnusedVariables": "error" }
+                    }
+                }
+            }"#,
+        );
+
+        let packages = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .packages;
+        let expected = [
+            RuleSourceKind::Eslint,
+            RuleSourceKind::EslintRegexp,
+            RuleSourceKind::EslintTypeScript,
+        ]
+        .map(|kind| kind.package_name().unwrap());
+
+        assert_eq!(packages, BTreeSet::from(expected));
+        assert_eq!(
+            RuleSourceKind::EslintTypeScript.package_name(),
+            Some("typescript-eslint")
+        );
+        assert_eq!(RuleSourceKind::Clippy.package_name(), None);
+    }
+
+    #[test]
+    fn plugins_not_imported_yet_still_name_their_rules() {
+        let kind = RuleSourceKind::EslintReactHooks;
+
+        assert_eq!(kind.as_namespace(), Some("react-hooks"));
+        assert_eq!(kind.package_name(), Some("eslint-plugin-react-hooks"));
+        assert_eq!(kind.to_ident(), None);
+        assert!(kind.is_legacy());
+        assert!(!kind.is_resolvable());
+    }
+
+    #[test]
+    fn banner_records_the_pinned_biome_revision() {
+        let manifest = include_str!("../Cargo.toml");
+        assert!(manifest.contains(&format!(r#"rev = "{BIOME_REVISION}""#)));
+
+        let banner = banner("\n");
+        assert!(banner.starts_with(concat!(
+            "// Generated by biome-eject ",
+            env!("CARGO_PKG_VERSION"),
+            "\n"
+        )));
+        assert!(banner.contains(BIOME_REVISION));
+    }
+
+    #[test]
+    fn suspicious_severities_are_read_from_the_suspicious_group() {
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": {
+                        "performance": { "noDelete": "error" },
+                        "suspicious": { "noDoubleEquals": "warn" }
+                    }
+                }
+            }"#,
+        );
+        let rules = config.get_linter_rules();
+
+        assert_eq!(
+            get_configured_severity(&rules, "suspicious", "noDoubleEquals"),
+            Some(Severity::Warning)
+        );
+        assert_eq!(
+            get_configured_severity(&rules, "performance", "noDelete"),
+            Some(Severity::Error)
+        );
+        assert_eq!(
+            get_configured_severity(&rules, "suspicious", "noDelete"),
+            None
+        );
+    }
+
+    #[test]
+    fn config_without_rules_is_empty() {
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let config =
+            config(r#"{ "linter": { "enabled": true, "rules": { "recommended": false } } }"#);
+
+        let eslint_config = build_eslint_config(&registry, &config, &options()).unwrap();
+        assert!(eslint_config.config.contains("defineConfig({"));
+        assert!(eslint_config.config.contains("rules: {}"));
+        assert!(!eslint_config.config.contains("plugins"));
+        assert!(eslint_config.rules.is_empty());
+    }
+
+    #[test]
+    fn config_of_the_core_rules_only_has_no_plugins() {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            ("suspicious", "noDoubleEquals", Severity::Error, &["eqeqeq"]),
+        ]);
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": {
+                        "recommended": false,
+                        "suspicious": { "noDebugger": "error", "noDoubleEquals": "warn" }
+                    }
+                }
+            }"#,
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options()).unwrap();
+        assert!(eslint_config.config.contains(r#""no-debugger": "error""#));
+        assert_eq!(eslint_config.rules["eqeqeq"], EslintLevel::Warn);
+        assert!(!eslint_config.config.contains("plugins"));
+        assert_eq!(eslint_config.packages, BTreeSet::from(["eslint"]));
+    }
+
+    /// Resolves the level of `suspicious/noDebugger`, which is at the warning severity by default
+    /// here to tell the layers apart.
+    fn resolved_level(json: &str) -> EslintLevel {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Warning,
+                &["no-debugger"],
+            ),
+            ("suspicious", "noDoubleEquals", Severity::Error, &["eqeqeq"]),
+        ]);
+        let rules = config(json).get_linter_rules();
+        let enabled_rules = enabled_rules(&registry, &rules);
+        let info = &registry.groups["suspicious"]["noDebugger"];
+
+        resolve_severity(
+            &rules,
+            &enabled_rules,
+            "suspicious",
+            "noDebugger",
+            info,
+            &LevelMapping::default(),
+        )
+    }
+
+    #[test]
+    fn severity_of_the_rule_takes_precedence() {
+        let level = resolved_level(
+            r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error", "noDoubleEquals": "warn" } } } }"#,
+        );
+
+        assert_eq!(level, EslintLevel::Error);
+    }
+
+    #[test]
+    fn severity_of_the_group_applies_to_its_rules() {
+        let level = resolved_level(r#"{ "linter": { "rules": { "suspicious": "error" } } }"#);
+
+        assert_eq!(level, EslintLevel::Error);
+    }
+
+    #[test]
+    fn severity_defaults_to_the_metadata() {
+        // Enabled without any severity
+        let level = resolved_level(
+            r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "on" } } } }"#,
+        );
+        assert_eq!(level, EslintLevel::Warn);
+
+        // Enabled by the recommended preset
+        let level = resolved_level(r#"{ "linter": { "rules": { "recommended": true } } }"#);
+        assert_eq!(level, EslintLevel::Warn);
+    }
+
+    #[test]
+    fn rules_not_enabled_are_off() {
+        let level = resolved_level(r#"{ "linter": { "rules": { "recommended": false } } }"#);
+        assert_eq!(level, EslintLevel::Off);
+
+        let level = resolved_level(
+            r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#,
+        );
+        assert_eq!(level, EslintLevel::Off);
+    }
+
+    #[test]
+    fn eslint_react_rules_are_registered_under_the_umbrella_plugin() {
+        let registry = registry(&[(
+            "security",
+            "noDangerouslySetInnerHtml",
+            Severity::Error,
+            &["@eslint-react/dom/no-dangerously-set-innerhtml"],
+        )]);
+        let config = config(
+            r#"{ "linter": { "rules": { "security": { "noDangerouslySetInnerHtml": "error" } } } }"#,
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .config;
+        assert!(
+            eslint_config.contains(r#"import eslintReact from "@eslint-react/eslint-plugin";"#)
+        );
+        assert!(eslint_config.contains(r#""@eslint-react": eslintReact"#));
+        assert!(
+            eslint_config.contains(r#""@eslint-react/dom/no-dangerously-set-innerhtml": "error""#)
+        );
+    }
+
+    #[test]
+    fn rules_are_annotated_with_their_docs() {
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let config =
+            config(r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#);
+        let options = EjectOptions {
+            include_metadata_comment: true,
+            ..options()
+        };
+
+        let eslint_config = build_eslint_config(&registry, &config, &options)
+            .unwrap()
+            .config;
+        let line = eslint_config
+            .lines()
+            .find(|line| line.contains(r#""no-debugger": "error""#))
+            .unwrap();
+        assert!(line.ends_with("// https://eslint.org/docs/latest/rules/no-debugger"));
+    }
+
+    #[test]
+    fn core_rules_are_not_namespaced() {
+        assert_eq!(
+            RuleSourceKind::Eslint.to_namespaced_rule_name("eqeqeq"),
+            "eqeqeq"
+        );
+
+        let registry = registry(&[
+            ("suspicious", "noDoubleEquals", Severity::Error, &["eqeqeq"]),
+            (
+                "suspicious",
+                "noExplicitAny",
+                Severity::Error,
+                &["@typescript-eslint/no-explicit-any"],
+            ),
+        ]);
+        let config = config(
+            r#"{ "linter": { "rules": { "suspicious": { "noDoubleEquals": "error", "noExplicitAny": "error" } } } }"#,
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options()).unwrap();
+        assert!(eslint_config.rules.contains_key("eqeqeq"));
+        assert!(!eslint_config.config.contains("eslint/eqeqeq"));
+
+        // Only the plugin is registered, not the built-in rules
+        let plugins = eslint_config
+            .config
+            .lines()
+            .skip_while(|line| !line.contains("plugins: {"))
+            .take_while(|line| !line.contains("rules: {"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(plugins.contains(r#""@typescript-eslint": tseslint"#));
+        assert!(!plugins.contains("eslint:"));
+        assert!(!plugins.contains(r#""eslint""#));
+    }
+
+    #[test]
+    fn import_rules_are_given_the_resolver_settings() {
+        let registry = registry(&[
+            (
+                "style",
+                "useImportType",
+                Severity::Error,
+                &["import/consistent-type-specifier-style"],
+            ),
+            (
+                "suspicious",
+                "noExplicitAny",
+                Severity::Error,
+                &["@typescript-eslint/no-explicit-any"],
+            ),
+        ]);
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": {
+                        "style": { "useImportType": "error" },
+                        "suspicious": { "noExplicitAny": "error" }
+                    }
+                }
+            }"#,
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .config;
+        assert!(eslint_config.contains("settings:"));
+        assert!(eslint_config.contains(r#""import/resolver""#));
+        assert!(eslint_config.contains("typescript: true"));
+
+        let options = EjectOptions {
+            no_import_resolver: true,
+            ..options()
+        };
+        let eslint_config = build_eslint_config(&registry, &config, &options)
+            .unwrap()
+            .config;
+        assert!(!eslint_config.contains(r#""import/resolver""#));
+    }
+
+    #[test]
+    fn resolver_falls_back_to_node_without_typescript() {
+        let registry = registry(&[(
+            "style",
+            "useImportType",
+            Severity::Error,
+            &["import/consistent-type-specifier-style"],
+        )]);
+        let config =
+            config(r#"{ "linter": { "rules": { "style": { "useImportType": "error" } } } }"#);
+
+        let eslint_config = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .config;
+        assert!(eslint_config.contains(r#""import/resolver""#));
+        assert!(eslint_config.contains("node: true"));
+    }
+
+    /// Every namespaced source with the identifier its plugin is imported as, if any.
+    ///
+    /// Changing a namespace breaks the configs already generated, so it has to be updated here.
+    const NAMESPACES: [(RuleSourceKind, &str, Option<&str>); 31] = [
+        (RuleSourceKind::EslintBarrelFiles, "barrel-files", None),
+        (RuleSourceKind::EslintGraphql, "@graphql-eslint", None),
+        (RuleSourceKind::EslintImport, "import", Some("importPlugin")),
+        (RuleSourceKind::EslintImportAccess, "import-access", None),
+        (RuleSourceKind::EslintJest, "jest", Some("jest")),
+        (RuleSourceKind::EslintJsDoc, "jsdoc", None),
+        (RuleSourceKind::EslintJsxA11y, "jsx-a11y", Some("jsxA11y")),
+        (RuleSourceKind::EslintMysticatea, "@mysticatea", None),
+        (RuleSourceKind::EslintN, "n", Some("n")),
+        (RuleSourceKind::EslintNext, "@next/next", None),
+        (RuleSourceKind::EslintNoSecrets, "no-secrets", None),
+        (RuleSourceKind::EslintPackageJson, "package-json", None),
+        (
+            RuleSourceKind::EslintPackageJsonDependencies,
+            "package-json-dependencies",
+            None,
+        ),
+        (
+            RuleSourceKind::EslintPerfectionist,
+            "perfectionist",
+            Some("perfectionist"),
+        ),
+        (RuleSourceKind::EslintQwik, "qwik", None),
+        (RuleSourceKind::EslintReact, "react", Some("react")),
+        (RuleSourceKind::EslintReactHooks, "react-hooks", None),
+        (
+            RuleSourceKind::EslintReactPreferFunctionComponent,
+            "react-prefer-function-component",
+            None,
+        ),
+        (RuleSourceKind::EslintReactRefresh, "react-refresh", None),
+        (RuleSourceKind::EslintReactX, "react-x", Some("reactX")),
+        (
+            RuleSourceKind::EslintReactXyz,
+            "@eslint-react",
+            Some("eslintReact"),
+        ),
+        (RuleSourceKind::EslintRegexp, "regexp", Some("regexp")),
+        (RuleSourceKind::EslintSolid, "solid", None),
+        (RuleSourceKind::EslintSonarJs, "sonarjs", Some("sonarjs")),
+        (
+            RuleSourceKind::EslintStylistic,
+            "@stylistic",
+            Some("stylistic"),
+        ),
+        (RuleSourceKind::EslintTurbo, "turbo", None),
+        (
+            RuleSourceKind::EslintTypeScript,
+            "@typescript-eslint",
+            Some("tseslint"),
+        ),
+        (
+            RuleSourceKind::EslintUnicorn,
+            "unicorn",
+            Some("eslintPluginUnicorn"),
+        ),
+        (RuleSourceKind::EslintUnusedImports, "unused-imports", None),
+        (RuleSourceKind::EslintVitest, "vitest", Some("vitest")),
+        (RuleSourceKind::EslintVueJs, "vue", Some("vue")),
+    ];
+
+    #[test]
+    fn every_namespace_is_covered() {
+        for kind in RuleSourceKind::ALL {
+            let expected = NAMESPACES
+                .iter()
+                .find(|(covered, ..)| *covered == kind)
+                .map(|&(_, namespace, _)| namespace);
+
+            assert_eq!(kind.as_namespace(), expected, "namespace of {kind:?}");
+        }
+    }
+
+    #[test]
+    fn namespaces_prefix_the_rules_and_key_the_plugins() {
+        let config =
+            config(r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#);
+
+        for (kind, namespace, ident) in NAMESPACES {
+            let rule_name = format!("{namespace}/synthetic-rule");
+            assert_eq!(kind.to_namespaced_rule_name("synthetic-rule"), rule_name);
+            assert_eq!(kind.to_namespaced_rule_name(&rule_name), rule_name);
+            assert_eq!(RuleSourceKind::from_namespaced_rule_name(&rule_name), kind);
+            assert_eq!(
+                kind.to_ident().as_ref().map(JsSyntaxToken::text_trimmed),
+                ident,
+                "identifier of {kind:?}"
+            );
+
+            let registry = registry(&[(
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &[rule_name.as_str()],
+            )]);
+            let eslint_config = build_eslint_config(&registry, &config, &options())
+                .unwrap()
+                .config;
+
+            let Some(ident) = ident else {
+                // Skipped rather than referring to a plugin never registered
+                assert!(
+                    !eslint_config.contains(&rule_name),
+                    "{kind:?} is not imported"
+                );
+                continue;
+            };
+
+            assert!(eslint_config.contains(&format!(r#""{rule_name}": "error""#)));
+            assert!(
+                [format!(r#""{namespace}""#), namespace.to_string()]
+                    .iter()
+                    .flat_map(|key| {
+                        [
+                            format!("{{ {key}: {ident}"),
+                            format!("{key}: {ident},"),
+                            format!("{key}: fixupPluginRules({ident})"),
+                        ]
+                    })
+                    .any(|member| eslint_config.contains(&member)),
+                "plugin of {kind:?} is registered under {namespace}"
+            );
+        }
+    }
+
+    #[test]
+    fn correctness_rules_enabled_without_severity_are_errors() {
+        let registry = registry(&[(
+            "correctness",
+            "noUnusedVariables",
+            Severity::Error,
+            &["no-unused-vars"],
+        )]);
+
+        // The severity falls back to the metadata of the rule, never to `warn`
+        let config = config(
+            r#"{ "linter": { "rules": { "correctness": { "noUnusedVariables": "on" } } } }"#,
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options()).unwrap();
+        assert_eq!(eslint_config.rules["no-unused-vars"], EslintLevel::Error);
+        assert!(
+            eslint_config
+                .config
+                .contains(r#""no-unused-vars": "error""#)
+        );
+    }
+
+    #[test]
+    fn global_ignores_are_emitted_with_the_helper() {
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let config = config(
+            r#"{
+                "files": { "includes": ["**", "!dist", "!coverage"] },
+                "linter": { "rules": { "suspicious": { "noDebugger": "error" } } }
+            }"#,
+        );
+        let options = EjectOptions {
+            flat_config_helpers: true,
+            ..options()
+        };
+
+        let eslint_config = build_eslint_config(&registry, &config, &options).unwrap();
+        assert!(eslint_config.packages.contains("@eslint/config-helpers"));
+
+        let config_source = eslint_config.config;
+        assert!(
+            config_source.contains(r#"import { globalIgnores } from "@eslint/config-helpers";"#)
+        );
+        assert!(config_source.contains(r#"globalIgnores(["dist", "coverage"])"#));
+        assert!(!config_source.contains("ignores:"));
+
+        // Ignoring the files globally, before any other config object
+        let helper = config_source.find("globalIgnores([").unwrap();
+        assert!(helper < config_source.find("rules: {").unwrap());
+
+        let config_source = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .config;
+        assert!(!config_source.contains("globalIgnores"));
+        assert!(config_source.contains(r#"ignores: ["dist", "coverage"]"#));
+    }
+
+    #[test]
+    fn only_the_rules_enabled_explicitly_are_emitted_without_recommended() {
+        let mut registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            ("suspicious", "noDoubleEquals", Severity::Error, &["eqeqeq"]),
+            ("suspicious", "noConsole", Severity::Error, &["no-console"]),
+            ("performance", "noDelete", Severity::Error, &["no-delete"]),
+        ]);
+        for rules in registry.groups.values_mut() {
+            for info in rules.values_mut() {
+                info.recommended = true;
+            }
+        }
+
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": {
+                        "recommended": false,
+                        "suspicious": { "noDebugger": "error", "noDoubleEquals": "warn" }
+                    }
+                }
+            }"#,
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options()).unwrap();
+        assert_eq!(
+            eslint_config.rules,
+            BTreeMap::from([
+                ("eqeqeq".to_string(), EslintLevel::Warn),
+                ("no-debugger".to_string(), EslintLevel::Error),
+            ])
+        );
+        assert!(!eslint_config.config.contains("no-console"));
+        assert!(!eslint_config.config.contains("no-delete"));
+    }
+
+    #[test]
+    fn levels_are_uniformly_wrapped_into_arrays() {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            ("suspicious", "noDoubleEquals", Severity::Error, &["eqeqeq"]),
+        ]);
+        let config = config(
+            r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "warn", "noDoubleEquals": "error" } } } }"#,
+        );
+        let options = EjectOptions {
+            uniform_array_levels: true,
+            ..options()
+        };
+
+        let eslint_config = build_eslint_config(&registry, &config, &options)
+            .unwrap()
+            .config;
+        assert!(eslint_config.contains(r#""no-debugger": ["warn"]"#));
+        assert!(
+            eslint_config.contains(r#"eqeqeq: ["error"]"#)
+                || eslint_config.contains(r#""eqeqeq": ["error"]"#)
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .config;
+        assert!(eslint_config.contains(r#""no-debugger": "warn""#));
+    }
+
+    #[test]
+    fn js_recommended_is_extended_with_the_deviations_only() {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            (
+                "correctness",
+                "noUnusedVariables",
+                Severity::Error,
+                &["no-unused-vars"],
+            ),
+            ("suspicious", "noDoubleEquals", Severity::Error, &["eqeqeq"]),
+        ]);
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": {
+                        "suspicious": { "noDebugger": "error", "noDoubleEquals": "error" },
+                        "correctness": { "noUnusedVariables": "warn" }
+                    }
+                }
+            }"#,
+        );
+        let options = EjectOptions {
+            extend_js_recommended: true,
+            ..options()
+        };
+
+        let eslint_config = build_eslint_config(&registry, &config, &options).unwrap();
+        assert!(eslint_config.packages.contains("@eslint/js"));
+
+        let config = eslint_config.config;
+        assert!(config.contains(r#"import js from "@eslint/js";"#));
+        assert!(config.contains("js.configs.recommended,"));
+
+        // Left to the preset as it is the same
+        assert!(!config.contains(r#""no-debugger""#));
+
+        // Overridden as they deviate from the preset, or are not in it
+        assert!(config.contains(r#""no-unused-vars": "warn""#));
+        assert!(config.contains(r#""for-direction": "off""#));
+        assert!(config.contains("eqeqeq"));
+
+        let preset = config.find("js.configs.recommended,").unwrap();
+        assert!(preset < config.find("rules: {").unwrap());
+    }
+
+    #[test]
+    fn complement_of_the_plugin_is_turned_off() {
+        let registry = registry(&[(
+            "suspicious",
+            "noExplicitAny",
+            Severity::Error,
+            &["@typescript-eslint/no-explicit-any"],
+        )]);
+        let config =
+            config(r#"{ "linter": { "rules": { "suspicious": { "noExplicitAny": "error" } } } }"#);
+        let options = EjectOptions {
+            explicit_off_complement: vec!["@typescript-eslint".to_string()],
+            ..options()
+        };
+
+        let eslint_config = build_eslint_config(&registry, &config, &options)
+            .unwrap()
+            .config;
+        assert!(eslint_config.contains(r#""@typescript-eslint/no-explicit-any": "error""#));
+        assert!(eslint_config.contains(r#""@typescript-eslint/no-floating-promises": "off""#));
+        assert!(eslint_config.contains(r#""@typescript-eslint/ban-ts-comment": "off""#));
+
+        let eslint_config = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .config;
+        assert!(!eslint_config.contains("@typescript-eslint/no-floating-promises"));
+    }
+
+    #[test]
+    fn rules_contradicting_the_preset_are_kept_as_overrides() {
+        let mut rules = BTreeMap::from([
+            (
+                "no-debugger".to_string(),
+                RuleEntry::new(EslintLevel::Error),
+            ),
+            ("no-empty".to_string(), RuleEntry::new(EslintLevel::Warn)),
+            ("eqeqeq".to_string(), RuleEntry::new(EslintLevel::Error)),
+        ]);
+
+        let contradictions = subtract_preset(&mut rules, &["no-debugger", "no-empty", "no-undef"]);
+        assert_eq!(contradictions, [("no-empty", EslintLevel::Warn)]);
+
+        let levels: BTreeMap<_, _> = rules
+            .iter()
+            .map(|(name, entry)| (name.as_str(), entry.level))
+            .collect();
+        assert_eq!(
+            levels,
+            BTreeMap::from([
+                ("eqeqeq", EslintLevel::Error),
+                ("no-empty", EslintLevel::Warn),
+                ("no-undef", EslintLevel::Off),
+            ])
+        );
+    }
+
+    #[test]
+    fn rules_missing_in_the_registry_are_unknown() {
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let config = config(
+            r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error", "noConsole": "error" } } } }"#,
+        );
+
+        let enabled_rules = enabled_rules(&registry, &config.get_linter_rules());
+        assert_eq!(
+            unknown_rules(&registry, &enabled_rules),
+            ["suspicious/noConsole"]
+        );
+
+        // Not emitted, as there is nothing known to translate it into
+        let eslint_config = build_eslint_config(&registry, &config, &options()).unwrap();
+        assert_eq!(
+            eslint_config.rules.keys().collect::<Vec<_>>(),
+            ["no-debugger"]
+        );
+    }
+
+    #[test]
+    fn local_files_are_imported_relative_to_the_config() {
+        let specifier = |output: &str, file: &str| {
+            let options = EjectOptions {
+                root: "/repo".into(),
+                output: output.into(),
+                ..options()
+            };
+
+            local_specifier(&options, Path::new(file))
+        };
+
+        assert_eq!(
+            specifier("eslint.config.mjs", "configs/base.mjs"),
+            "./configs/base.mjs"
+        );
+        assert_eq!(
+            specifier("eslint.config.mjs", "/repo/configs/base.mjs"),
+            "./configs/base.mjs"
+        );
+        assert_eq!(
+            specifier("lint/eslint.config.mjs", "configs/base.mjs"),
+            "../configs/base.mjs"
+        );
+        assert_eq!(
+            specifier("lint/eslint.config.mjs", "lint/base.mjs"),
+            "./base.mjs"
+        );
+        assert_eq!(
+            specifier("eslint.config.mjs", "/shared/base.mjs"),
+            "/shared/base.mjs"
+        );
+    }
+
+    #[test]
+    fn base_config_is_imported_and_spread_first() {
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let config =
+            config(r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#);
+        let options = EjectOptions {
+            base_config: Some("configs/base.mjs".into()),
+            ..options()
+        };
+
+        let eslint_config = build_eslint_config(&registry, &config, &options)
+            .unwrap()
+            .config;
+        assert!(eslint_config.contains(r#"import base from "./configs/base.mjs";"#));
+        assert!(eslint_config.contains(r#"import { defineConfig } from "eslint/config";"#));
+
+        let spread = eslint_config.find("...base").unwrap();
+        assert!(spread < eslint_config.find("rules: {").unwrap());
+    }
+
+    #[test]
+    fn gitignore_is_read_at_runtime() {
+        let root = crate::testing::temp_dir("gitignore-runtime");
+        std::fs::write(root.join(".gitignore"), "/dist\n").unwrap();
+
+        let registry = registry(&[]);
+        let config =
+            config(r#"{ "vcs": { "enabled": true, "clientKind": "git", "useIgnoreFile": true } }"#);
+        let options = EjectOptions {
+            root,
+            gitignore: GitignoreMode::Runtime,
+            ..options()
+        };
+
+        let eslint_config = build_eslint_config(&registry, &config, &options).unwrap();
+        assert!(eslint_config.packages.contains("@eslint/compat"));
+
+        let config_source = eslint_config.config;
+        assert!(config_source.contains(r#"import { includeIgnoreFile } from "@eslint/compat";"#));
+        assert!(config_source.contains(r#"import { fileURLToPath } from "node:url";"#));
+        assert!(config_source.contains(
+            r#"includeIgnoreFile(fileURLToPath(new URL("./.gitignore", import.meta.url)))"#
+        ));
+        assert!(!config_source.contains("ignores:"));
+
+        let options = EjectOptions {
+            gitignore: GitignoreMode::Inline,
+            ..options
+        };
+        let config_source = build_eslint_config(&registry, &config, &options)
+            .unwrap()
+            .config;
+        assert!(!config_source.contains("includeIgnoreFile"));
+        assert!(config_source.contains(r#"ignores: ["dist"]"#));
+    }
+
+    #[test]
+    fn only_the_deviations_from_the_defaults_are_emitted() {
+        let mut registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            (
+                "suspicious",
+                "noConsole",
+                Severity::Warning,
+                &["no-console"],
+            ),
+            ("suspicious", "noDoubleEquals", Severity::Error, &["eqeqeq"]),
+        ]);
+        for info in registry.groups.get_mut("suspicious").unwrap().values_mut() {
+            info.recommended = true;
+        }
+
+        // noDebugger is left as default, noConsole is bumped, and noDoubleEquals is turned off
+        let config = config(
+            r#"{ "linter": { "rules": { "suspicious": { "noConsole": "error", "noDoubleEquals": "off" } } } }"#,
+        );
+        let options = EjectOptions {
+            only_deviations: true,
+            ..options()
+        };
+
+        let eslint_config = build_eslint_config(&registry, &config, &options).unwrap();
+        assert_eq!(
+            eslint_config.rules,
+            BTreeMap::from([
+                ("eqeqeq".to_string(), EslintLevel::Off),
+                ("no-console".to_string(), EslintLevel::Error),
+            ])
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options()).unwrap();
+        assert_eq!(eslint_config.rules["no-debugger"], EslintLevel::Error);
+    }
+
+    #[test]
+    fn objects_of_any_size_are_valid() {
+        static RULES: [(&str, &str, &str); 5] = [
+            ("suspicious", "noDebugger", "n/no-debugger"),
+            ("suspicious", "noDoubleEquals", "react/no-double-equals"),
+            ("suspicious", "noConsole", "sonarjs/no-console"),
+            ("suspicious", "noExplicitAny", "unicorn/no-explicit-any"),
+            ("performance", "noDelete", "regexp/no-delete"),
+        ];
+
+        for count in [0, 1, 2, RULES.len()] {
+            let rules = &RULES[..count];
+            let registry = registry(
+                &rules
+                    .iter()
+                    .map(|(group, rule, source)| {
+                        (*group, *rule, Severity::Error, std::slice::from_ref(source))
+                    })
+                    .collect::<Vec<_>>(),
+            );
+
+            let mut groups = BTreeMap::<_, BTreeMap<_, _>>::new();
+            for (group, rule, _) in rules {
+                groups.entry(*group).or_default().insert(*rule, "error");
+            }
+            let config = config(&serde_json::json!({ "linter": { "rules": groups } }).to_string());
+
+            let eslint_config = build_eslint_config(&registry, &config, &options())
+                .unwrap()
+                .config;
+            let parsed = biome_js_parser::parse_module(
+                &eslint_config,
+                biome_js_parser::JsParserOptions::default(),
+            );
+            assert!(!parsed.has_errors(), "{count} entries:\n{eslint_config}");
+
+            for (_, _, source) in rules {
+                assert_eq!(
+                    eslint_config
+                        .matches(&format!(r#""{source}": "error""#))
+                        .count(),
+                    1,
+                    "{source} in {count} entries"
+                );
+            }
+
+            assert_eq!(eslint_config.contains("plugins"), count > 0);
+            assert!(!eslint_config.contains(",,"));
+            assert!(!eslint_config.contains("{,"));
+            assert!(!eslint_config.contains("[,"));
+        }
+    }
+
+    #[test]
+    fn react_plugin_is_imported_under_its_namespace() {
+        let registry = registry(&[(
+            "correctness",
+            "useJsxKeyInIterable",
+            Severity::Error,
+            &["react/jsx-key"],
+        )]);
+        let config = config(
+            r#"{ "linter": { "rules": { "correctness": { "useJsxKeyInIterable": "error" } } } }"#,
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options()).unwrap();
+        assert!(eslint_config.packages.contains("eslint-plugin-react"));
+
+        let config_source = eslint_config.config;
+        assert!(config_source.contains(r#"import react from "eslint-plugin-react";"#));
+        assert!(config_source.contains(r#""react/jsx-key": "error""#));
+        assert!(
+            config_source.contains("react: react") || config_source.contains(r#""react": react"#)
+        );
+    }
+
+    #[test]
+    fn cognitive_complexity_threshold_is_passed_to_sonarjs() {
+        let registry = registry(&[(
+            "complexity",
+            "noExcessiveCognitiveComplexity",
+            Severity::Warning,
+            &["sonarjs/cognitive-complexity"],
+        )]);
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": {
+                        "complexity": {
+                            "noExcessiveCognitiveComplexity": {
+                                "level": "error",
+                                "options": { "maxAllowedComplexity": 10 }
+                            }
+                        }
+                    }
+                }
+            }"#,
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options()).unwrap();
+        assert!(eslint_config.packages.contains("eslint-plugin-sonarjs"));
+
+        let config_source = eslint_config.config;
+        assert!(config_source.contains(r#"import sonarjs from "eslint-plugin-sonarjs";"#));
+        assert!(config_source.contains(r#""sonarjs/cognitive-complexity": ["error", 10]"#));
+    }
+
+    #[test]
+    fn sonarjs_plugin_is_registered_under_its_namespace() {
+        let registry = registry(&[(
+            "suspicious",
+            "noDoubleEquals",
+            Severity::Error,
+            &["sonarjs/no-equals"],
+        )]);
+        let config =
+            config(r#"{ "linter": { "rules": { "suspicious": { "noDoubleEquals": "error" } } } }"#);
+
+        let config_source = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .config;
+        assert!(config_source.contains(r#"import sonarjs from "eslint-plugin-sonarjs";"#));
+        assert!(config_source.contains(r#""sonarjs/no-equals": "error""#));
+        assert!(
+            config_source.contains("sonarjs: sonarjs")
+                || config_source.contains(r#""sonarjs": sonarjs"#)
+        );
+    }
+
+    #[test]
+    fn stylistic_plugin_is_registered_under_the_quoted_namespace() {
+        let registry = registry(&[("style", "useConst", Severity::Error, &["@stylistic/semi"])]);
+        let config = config(r#"{ "linter": { "rules": { "style": { "useConst": "error" } } } }"#);
+
+        let eslint_config = build_eslint_config(&registry, &config, &options()).unwrap();
+        assert!(eslint_config.packages.contains("@stylistic/eslint-plugin"));
+
+        let config_source = eslint_config.config;
+        assert!(config_source.contains(r#"import stylistic from "@stylistic/eslint-plugin";"#));
+        assert!(config_source.contains(r#""@stylistic": stylistic"#));
+        assert!(config_source.contains(r#""@stylistic/semi": "error""#));
+    }
+
+    #[test]
+    fn rules_are_prefixed_with_exactly_one_namespace() {
+        for kind in RuleSourceKind::ALL {
+            let Some(namespace) = kind.as_namespace() else {
+                continue;
+            };
+
+            let expected = format!("{namespace}/some-rule");
+            for name in ["some-rule", expected.as_str()] {
+                let namespaced = kind.to_namespaced_rule_name(name);
+
+                assert_eq!(namespaced, expected, "{kind:?} prefixing {name}");
+                assert_eq!(kind.to_bare_rule_name(&namespaced), "some-rule");
+            }
+        }
+
+        // Sub-plugins keep their own segment under the umbrella namespace
+        assert_eq!(
+            RuleSourceKind::EslintReactXyz
+                .to_namespaced_rule_name("@eslint-react/dom/no-find-dom-node"),
+            "@eslint-react/dom/no-find-dom-node"
+        );
+
+        let registry = registry(&[(
+            "style",
+            "useConst",
+            Severity::Error,
+            &["unicorn/prefer-const"],
+        )]);
+        let config = config(r#"{ "linter": { "rules": { "style": { "useConst": "error" } } } }"#);
+
+        let eslint_config = build_eslint_config(&registry, &config, &options()).unwrap();
+        assert_eq!(
+            eslint_config.rules.keys().collect::<Vec<_>>(),
+            ["unicorn/prefer-const"]
+        );
+        assert!(!eslint_config.config.contains("unicorn/unicorn/"));
+    }
+
+    #[test]
+    fn vue_rules_are_scoped_to_the_vue_files() {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            (
+                "suspicious",
+                "noDoubleEquals",
+                Severity::Error,
+                &["vue/eqeqeq"],
+            ),
+        ]);
+        let config = config(
+            r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error", "noDoubleEquals": "error" } } } }"#,
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options()).unwrap();
+        assert!(eslint_config.packages.contains("eslint-plugin-vue"));
+        assert!(eslint_config.packages.contains("vue-eslint-parser"));
+
+        let config_source = eslint_config.config;
+        assert!(config_source.contains(r#"import vue from "eslint-plugin-vue";"#));
+        assert!(config_source.contains(r#"import vueParser from "vue-eslint-parser";"#));
+        assert!(config_source.contains("vue: vue") || config_source.contains(r#""vue": vue"#));
+
+        let (base, vue) = config_source.split_once(r#"files: ["**/*.vue"]"#).unwrap();
+        assert!(base.contains(r#""no-debugger": "error""#));
+        assert!(!base.contains(r#""vue/eqeqeq""#));
+
+        assert!(vue.contains("parser: vueParser"));
+        assert!(vue.contains(r#"processor: "vue/vue""#));
+        assert!(vue.contains(r#""vue/eqeqeq": "error""#));
+    }
+
+    #[test]
+    fn every_source_is_emitted_if_selected() {
+        let registry = registry(&[(
+            "correctness",
+            "noUnusedVariables",
+            Severity::Error,
+            &[
+                "no-unused-vars",
+                "@typescript-eslint/no-unused-vars",
+                "no-unused-vars",
+            ],
+        )]);
+        let config = config(
+            r#"{ "linter": { "rules": { "correctness": { "noUnusedVariables": "error" } } } }"#,
+        );
+        let rule_names = |source_selection: SourceSelection| {
+            let options = EjectOptions {
+                source_selection,
+                ..options()
+            };
+
+            build_eslint_config(&registry, &config, &options)
+                .unwrap()
+                .rules
+                .into_keys()
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            rule_names(SourceSelection::All),
+            ["@typescript-eslint/no-unused-vars", "no-unused-vars"]
+        );
+        assert_eq!(rule_names(SourceSelection::First), ["no-unused-vars"]);
+        assert_eq!(
+            rule_names(SourceSelection::PreferNamespace(
+                "@typescript-eslint".to_string()
+            )),
+            ["@typescript-eslint/no-unused-vars"]
+        );
+    }
+
+    #[test]
+    fn config_is_built_as_a_whole_module() {
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let config =
+            config(r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#);
+
+        let eslint_config = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .config;
+        let module = eslint_config.strip_prefix(&banner("\n")).unwrap();
+
+        assert!(module.starts_with("import { defineConfig } from \"eslint/config\";\n"));
+        assert!(module.contains("export default defineConfig({"));
+        assert!(module.ends_with("});\n"));
+        assert!(
+            !biome_js_parser::parse_module(module, biome_js_parser::JsParserOptions::default())
+                .has_errors()
+        );
+
+        // Built the same every time, without reading anything but the config
+        assert_eq!(
+            build_eslint_config(&registry, &config, &options())
+                .unwrap()
+                .config,
+            eslint_config
+        );
+    }
+
+    #[test]
+    fn rules_of_plugins_not_imported_are_omitted() {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            (
+                "suspicious",
+                "noConsole",
+                Severity::Error,
+                &["solid/no-console"],
+            ),
+        ]);
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": { "suspicious": { "noDebugger": "error", "noConsole": "error" } }
+                }
+            }"#,
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options()).unwrap();
+
+        assert_eq!(
+            eslint_config.rules.keys().collect::<Vec<_>>(),
+            ["no-debugger"]
+        );
+        assert!(!eslint_config.config.contains("solid"));
+        assert_eq!(
+            eslint_config.unresolved,
+            BTreeSet::from(["suspicious/noConsole".to_string()])
+        );
+        assert!(eslint_config.unmapped.is_empty());
+    }
+
+    #[test]
+    fn group_enabled_as_a_whole_is_expanded_into_its_rules() {
+        let registry = registry(&[
+            (
+                "complexity",
+                "noExcessiveCognitiveComplexity",
+                Severity::Warning,
+                &["complexity"],
+            ),
+            (
+                "complexity",
+                "noUselessFragments",
+                Severity::Error,
+                &["no-useless-fragments"],
+            ),
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+        ]);
+
+        // At the severity of each rule in its metadata
+        let config =
+            config(r#"{ "linter": { "rules": { "recommended": false, "complexity": "on" } } }"#);
+        assert_eq!(
+            build_eslint_config(&registry, &config, &options())
+                .unwrap()
+                .rules,
+            BTreeMap::from([
+                ("complexity".to_string(), EslintLevel::Warn),
+                ("no-useless-fragments".to_string(), EslintLevel::Error),
+            ])
+        );
+
+        // At the severity configured for the group
+        let config = crate::testing::config(
+            r#"{ "linter": { "rules": { "recommended": false, "complexity": "error" } } }"#,
+        );
+        assert_eq!(
+            build_eslint_config(&registry, &config, &options())
+                .unwrap()
+                .rules,
+            BTreeMap::from([
+                ("complexity".to_string(), EslintLevel::Error),
+                ("no-useless-fragments".to_string(), EslintLevel::Error),
+            ])
+        );
+    }
+
+    #[test]
+    fn rules_turned_off_explicitly_are_off_unless_skipped() {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            ("suspicious", "noConsole", Severity::Error, &["no-console"]),
+            ("suspicious", "noDoubleEquals", Severity::Error, &["eqeqeq"]),
+        ]);
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": { "suspicious": { "noDebugger": "off", "noConsole": "error" } }
+                }
+            }"#,
+        );
+
+        assert_eq!(
+            build_eslint_config(&registry, &config, &options())
+                .unwrap()
+                .rules,
+            BTreeMap::from([
+                ("no-console".to_string(), EslintLevel::Error),
+                ("no-debugger".to_string(), EslintLevel::Off),
+            ])
+        );
+
+        let options = EjectOptions {
+            skip_off: true,
+            ..options()
+        };
+        assert_eq!(
+            build_eslint_config(&registry, &config, &options)
+                .unwrap()
+                .rules,
+            BTreeMap::from([("no-console".to_string(), EslintLevel::Error)])
+        );
+    }
+
+    #[test]
+    fn custom_level_mapping_changes_the_emitted_level() {
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let config =
+            config(r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "info" } } } }"#);
+
+        assert_eq!(
+            build_eslint_config(&registry, &config, &options())
+                .unwrap()
+                .rules,
+            BTreeMap::from([("no-debugger".to_string(), EslintLevel::Warn)])
+        );
+
+        let options = EjectOptions {
+            level_mapping: LevelMapping {
+                information: EslintLevel::Error,
+                ..LevelMapping::default()
+            },
+            ..options()
+        };
+        let eslint_config = build_eslint_config(&registry, &config, &options).unwrap();
+        assert_eq!(
+            eslint_config.rules,
+            BTreeMap::from([("no-debugger".to_string(), EslintLevel::Error)])
+        );
+        assert!(eslint_config.config.contains(r#""no-debugger": "error""#));
+    }
+
+    #[test]
+    fn numeric_levels_are_emitted_as_numbers() {
+        use biome_js_syntax::{JsPropertyObjectMember, JsSyntaxKind};
+
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let config =
+            config(r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#);
+        let options = EjectOptions {
+            numeric_levels: true,
+            ..options()
+        };
+
+        let eslint_config = build_eslint_config(&registry, &config, &options)
+            .unwrap()
+            .config;
+        assert!(eslint_config.contains(r#""no-debugger": 2"#));
+
+        let module = biome_js_parser::parse_module(
+            &eslint_config,
+            biome_js_parser::JsParserOptions::default(),
+        );
+        let levels: Vec<_> = module
+            .syntax()
+            .descendants()
+            .filter_map(JsPropertyObjectMember::cast)
+            .filter(|member| {
+                member
+                    .name()
+                    .is_ok_and(|name| name.syntax().text_trimmed() == r#""no-debugger""#)
+            })
+            .map(|member| member.value().unwrap().syntax().kind())
+            .collect();
+        assert_eq!(levels, [JsSyntaxKind::JS_NUMBER_LITERAL_EXPRESSION]);
+    }
 }
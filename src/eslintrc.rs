@@ -0,0 +1,244 @@
+use std::collections::BTreeMap;
+
+use biome_configuration::Configuration;
+use serde_json::{Map, Value, json};
+
+use crate::eslint::{BaseRules, RuleSourceKind, collect_base_rules};
+use crate::levels::eslint_codec;
+use crate::options::{EjectOptions, EslintrcFormat};
+use crate::output::{apply_newline, resolve_newline};
+use crate::registry::RuleRegistry;
+
+/// Builds a legacy `.eslintrc.json` for the projects still on ESLint 8.
+///
+/// Only the rules at the top level are ejected, as the environments are declared by `env` instead
+/// of importing the globals in the flat config.
+pub fn build_eslintrc_config(
+    registry: &RuleRegistry,
+    config: &Configuration,
+    options: &EjectOptions,
+) -> String {
+    let base_rules = collect_base_rules(registry, config, options);
+
+    build_eslintrc_config_from(config, options, &base_rules)
+}
+
+/// Builds the eslintrc from the rules resolved already, e.g. shared with the other targets.
+pub fn build_eslintrc_config_from(
+    config: &Configuration,
+    options: &EjectOptions,
+    base_rules: &BaseRules,
+) -> String {
+    let BaseRules {
+        sources,
+        rules,
+        scoped_rules,
+        ..
+    } = base_rules;
+
+    let mut eslintrc = Map::new();
+    eslintrc.insert("root".to_string(), Value::Bool(true));
+
+    // { "browser": true, "node": true }
+    if !options.legacy_env.is_empty() {
+        let env: Map<String, Value> = options
+            .legacy_env
+            .iter()
+            .map(|env| (env.clone(), Value::Bool(true)))
+            .collect();
+
+        eslintrc.insert("env".to_string(), Value::Object(env));
+    }
+
+    // Plugins are referred by their namespaces, resolved by the naming convention of ESLint 8
+    let plugins: Vec<_> = sources
+        .iter()
+        .filter(|source| **source != RuleSourceKind::Eslint)
+        .filter_map(RuleSourceKind::as_namespace)
+        .collect();
+
+    if !plugins.is_empty() {
+        eslintrc.insert("plugins".to_string(), json!(plugins));
+    }
+
+    // Scoped groups are applied to all the files, as the overrides are not generated
+    let codec = eslint_codec(options.numeric_levels);
+    let rules_json: Map<String, Value> = rules
+        .iter()
+        .chain(scoped_rules.values().flatten())
+        .map(|(name, entry)| {
+            let level = codec.encode(entry.level);
+            if entry.options.is_empty() {
+                (name.clone(), level)
+            } else {
+                let value = std::iter::once(level)
+                    .chain(entry.options.iter().cloned())
+                    .collect();
+                (name.clone(), Value::Array(value))
+            }
+        })
+        .collect();
+
+    eslintrc.insert("rules".to_string(), Value::Object(rules_json));
+
+    let printed = match options.format {
+        EslintrcFormat::Json => serde_json::to_string_pretty(&eslintrc).unwrap(),
+        EslintrcFormat::Json5 => {
+            let comments: BTreeMap<&str, &str> = rules
+                .iter()
+                .chain(scoped_rules.values().flatten())
+                .filter_map(|(name, entry)| Some((name.as_str(), entry.comment.as_deref()?)))
+                .collect();
+
+            let mut printed = String::new();
+            write_json5_object(&mut printed, &eslintrc, 0, None, &comments);
+            printed
+        }
+    };
+
+    apply_newline(&printed, resolve_newline(options.line_ending, config))
+}
+
+/// Writes the object in JSON5, annotating its members with the comments keyed by their names.
+///
+/// The comments of the rules are passed down to the top-level `rules` object.
+fn write_json5_object(
+    out: &mut String,
+    object: &Map<String, Value>,
+    depth: usize,
+    comments: Option<&BTreeMap<&str, &str>>,
+    rule_comments: &BTreeMap<&str, &str>,
+) {
+    if object.is_empty() {
+        out.push_str("{}");
+        return;
+    }
+
+    let indent = "  ".repeat(depth + 1);
+
+    out.push_str("{\n");
+    for (key, value) in object {
+        out.push_str(&indent);
+        out.push_str(&json5_key(key));
+        out.push_str(": ");
+
+        match value {
+            Value::Object(members) => {
+                let comments = (depth == 0 && key == "rules").then_some(rule_comments);
+                write_json5_object(out, members, depth + 1, comments, rule_comments);
+            }
+            value => out.push_str(&serde_json::to_string(value).unwrap()),
+        }
+
+        // Trailing commas are allowed in JSON5, keeping the diff small on additions
+        out.push(',');
+
+        if let Some(comment) = comments.and_then(|comments| comments.get(key.as_str())) {
+            out.push_str(" // ");
+            out.push_str(comment);
+        }
+
+        out.push('\n');
+    }
+
+    out.push_str(&"  ".repeat(depth));
+    out.push('}');
+}
+
+/// Keys are left unquoted if they are valid identifiers, e.g. `root` but `"@stylistic/semi"`.
+fn json5_key(key: &str) -> String {
+    let mut chars = key.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+
+    if is_identifier {
+        key.to_string()
+    } else {
+        serde_json::to_string(key).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use biome_diagnostics::Severity;
+
+    use super::*;
+    use crate::testing::{config, options, registry};
+
+    #[test]
+    fn environments_are_declared_in_env() {
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let config =
+            config(r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#);
+        let options = EjectOptions {
+            legacy_env: vec![
+                "browser".to_string(),
+                "es2022".to_string(),
+                "node".to_string(),
+            ],
+            ..options()
+        };
+
+        let eslintrc: Value =
+            serde_json::from_str(&build_eslintrc_config(&registry, &config, &options)).unwrap();
+        assert_eq!(
+            eslintrc["env"],
+            json!({ "browser": true, "es2022": true, "node": true })
+        );
+        assert_eq!(eslintrc["rules"], json!({ "no-debugger": "error" }));
+
+        let eslintrc: Value =
+            serde_json::from_str(&build_eslintrc_config(&registry, &config, &options())).unwrap();
+        assert!(eslintrc.get("env").is_none());
+    }
+
+    #[test]
+    fn rules_are_annotated_in_json5() {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            ("suspicious", "noDoubleEquals", Severity::Error, &["eqeqeq"]),
+        ]);
+        let config = config(
+            r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error", "noDoubleEquals": "warn" } } } }"#,
+        );
+        let options = EjectOptions {
+            format: EslintrcFormat::Json5,
+            include_metadata_comment: true,
+            ..options()
+        };
+
+        let eslintrc = build_eslintrc_config(&registry, &config, &options);
+        assert!(eslintrc.starts_with("{\n"));
+        assert!(eslintrc.contains("\n  root: true,\n"));
+        assert!(eslintrc.contains(
+            "\n    \"no-debugger\": \"error\", // https://eslint.org/docs/latest/rules/no-debugger\n"
+        ));
+        assert!(
+            eslintrc.contains(
+                "\n    eqeqeq: \"warn\", // https://eslint.org/docs/latest/rules/eqeqeq\n"
+            )
+        );
+        assert!(eslintrc.trim_end().ends_with("  },\n}"));
+    }
+
+    #[test]
+    fn json5_keys_are_quoted_unless_identifiers() {
+        assert_eq!(json5_key("root"), "root");
+        assert_eq!(json5_key("$schema"), "$schema");
+        assert_eq!(json5_key("no-debugger"), r#""no-debugger""#);
+        assert_eq!(json5_key("@stylistic/semi"), r#""@stylistic/semi""#);
+        assert_eq!(json5_key("1st"), r#""1st""#);
+    }
+}
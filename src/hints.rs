@@ -0,0 +1,74 @@
+/// Best-effort suggestions for Biome rules without an ESLint source, keyed by the Biome rule name.
+///
+/// These are not exact equivalents, so they are never emitted into the config automatically.
+const UNMAPPED_HINTS: &[(&str, &str)] = &[
+    (
+        "noAccumulatingSpread",
+        "`unicorn/no-array-reduce` from `eslint-plugin-unicorn`",
+    ),
+    (
+        "noDelete",
+        "`@typescript-eslint/no-dynamic-delete` from `typescript-eslint`",
+    ),
+    (
+        "noExportsInTest",
+        "`jest/no-export` from `eslint-plugin-jest`",
+    ),
+    (
+        "noProcessGlobal",
+        "`n/prefer-global/process` from `eslint-plugin-n`",
+    ),
+    (
+        "noVoidTypeReturn",
+        "`@typescript-eslint/no-confusing-void-expression` from `typescript-eslint`",
+    ),
+    (
+        "useSortedClasses",
+        "`tailwindcss/classnames-order` from `eslint-plugin-tailwindcss`",
+    ),
+    (
+        "useSimplifiedLogicExpression",
+        "`no-implicit-coercion` and `no-extra-boolean-cast`",
+    ),
+    ("useStrictMode", "`strict`"),
+];
+
+/// Returns the suggestion for the Biome rule without an ESLint source, if any is curated.
+pub fn unmapped_hint(rule: &str) -> Option<&'static str> {
+    UNMAPPED_HINTS
+        .iter()
+        .find_map(|(name, hint)| (*name == rule).then_some(*hint))
+}
+
+#[cfg(test)]
+mod tests {
+    use biome_diagnostics::Severity;
+
+    use super::*;
+    use crate::interactive::Prompt;
+    use crate::testing::{config, options, registry};
+
+    #[test]
+    fn hints_are_curated() {
+        assert_eq!(
+            unmapped_hint("noDelete"),
+            Some("`@typescript-eslint/no-dynamic-delete` from `typescript-eslint`")
+        );
+        assert_eq!(unmapped_hint("noDebugger"), None);
+    }
+
+    #[test]
+    fn hints_are_suggested_for_the_unmapped_rules() {
+        let registry = registry(&[("performance", "noDelete", Severity::Error, &[])]);
+        let config =
+            config(r#"{ "linter": { "rules": { "performance": { "noDelete": "error" } } } }"#);
+
+        let mut output = Vec::new();
+        Prompt::new(&b"\n"[..], &mut output).mapping(&registry, &[config], &options());
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains(
+            "performance/noDelete has no ESLint equivalent (consider `@typescript-eslint/no-dynamic-delete` from `typescript-eslint`)"
+        ));
+    }
+}
@@ -0,0 +1,152 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use biome_configuration::Configuration;
+use serde_json::Value;
+
+use crate::options::GitignoreMode;
+
+/// Collects the patterns of the files ignored by the linter into a single list.
+///
+/// The sources are merged in the order of `files.includes`, the VCS ignore file, and
+/// `linter.includes`. Every pattern is kept only at its first occurrence, so the list never
+/// contains duplicates even when the sources overlap.
+///
+/// The VCS ignore file is left out if it is read at runtime instead.
+pub fn collect_ignores(
+    config: &Configuration,
+    root: &Path,
+    gitignore: GitignoreMode,
+) -> Vec<String> {
+    let files = serde_json::to_value(&config.files).unwrap_or_default();
+    let linter = serde_json::to_value(&config.linter).unwrap_or_default();
+
+    let vcs_ignores = match gitignore {
+        GitignoreMode::Inline => vcs_ignores(config, root),
+        GitignoreMode::Runtime => Vec::new(),
+    };
+
+    let mut seen = BTreeSet::<String>::new();
+
+    negated_includes(&files)
+        .chain(vcs_ignores)
+        .chain(negated_includes(&linter))
+        .filter(|pattern| seen.insert(pattern.clone()))
+        .collect()
+}
+
+/// Ignore patterns of an `includes` list, which are negated (`!dist`) in Biome.
+fn negated_includes(section: &Value) -> impl Iterator<Item = String> {
+    section
+        .get("includes")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|glob| {
+            let ignore = glob.as_str()?.strip_prefix('!')?;
+
+            // `!!` force-ignores the files even from the indexing, which is the same for ESLint
+            Some(ignore.strip_prefix('!').unwrap_or(ignore).to_string())
+        })
+}
+
+/// Path to the `.gitignore` respected by Biome, if any.
+pub fn vcs_ignore_file(config: &Configuration, root: &Path) -> Option<PathBuf> {
+    vcs_ignore_path(config, root).filter(|path| path.is_file())
+}
+
+/// Path to the `.gitignore` Biome would respect if the VCS integration uses it, whether it exists
+/// or not.
+pub fn vcs_ignore_path(config: &Configuration, root: &Path) -> Option<PathBuf> {
+    let vcs = serde_json::to_value(&config.vcs).unwrap_or_default();
+
+    let enabled = vcs.get("enabled").and_then(Value::as_bool) == Some(true);
+    let use_ignore_file = vcs.get("useIgnoreFile").and_then(Value::as_bool) == Some(true);
+    if !enabled || !use_ignore_file {
+        return None;
+    }
+
+    let root = root.join(vcs.get("root").and_then(Value::as_str).unwrap_or("."));
+
+    Some(root.join(".gitignore"))
+}
+
+/// Patterns of the `.gitignore` respected by Biome, translated for ESLint.
+fn vcs_ignores(config: &Configuration, root: &Path) -> Vec<String> {
+    let Some(contents) =
+        vcs_ignore_file(config, root).and_then(|path| fs::read_to_string(path).ok())
+    else {
+        return Vec::new();
+    };
+
+    contents
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(gitignore_to_eslint_pattern)
+        .collect()
+}
+
+/// Translates a `.gitignore` pattern into the glob pattern of ESLint.
+///
+/// Patterns without a slash match at any depth in `.gitignore`, while ESLint resolves every
+/// pattern from the directory of the config.
+fn gitignore_to_eslint_pattern(line: &str) -> String {
+    let (negation, pattern) = match line.strip_prefix('!') {
+        Some(pattern) => ("!", pattern),
+        None => ("", line),
+    };
+
+    let pattern = match pattern.strip_prefix('/') {
+        Some(anchored) => anchored.to_string(),
+        None if pattern.trim_end_matches('/').contains('/') => pattern.to_string(),
+        None => format!("**/{pattern}"),
+    };
+
+    format!("{negation}{pattern}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{config, temp_dir};
+
+    #[test]
+    fn overlapping_ignores_are_deduplicated() {
+        let root = temp_dir("overlapping-ignores");
+        fs::write(root.join(".gitignore"), "# build\n/dist\n*.log\n").unwrap();
+
+        let config = config(
+            r#"{
+                "vcs": { "enabled": true, "clientKind": "git", "useIgnoreFile": true },
+                "files": { "includes": ["**", "!dist", "!!node_modules"] },
+                "linter": { "includes": ["**", "!dist", "!**/*.log", "!coverage"] }
+            }"#,
+        );
+
+        assert_eq!(
+            collect_ignores(&config, &root, GitignoreMode::Inline),
+            ["dist", "node_modules", "**/*.log", "coverage"],
+        );
+    }
+
+    #[test]
+    fn runtime_gitignore_is_not_inlined() {
+        let root = temp_dir("runtime-gitignore");
+        fs::write(root.join(".gitignore"), "/build\n").unwrap();
+
+        let config = config(
+            r#"{
+                "vcs": { "enabled": true, "clientKind": "git", "useIgnoreFile": true },
+                "files": { "includes": ["**", "!dist"] }
+            }"#,
+        );
+
+        assert_eq!(
+            collect_ignores(&config, &root, GitignoreMode::Runtime),
+            ["dist"],
+        );
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::BTreeSet;
+use std::io::{self, BufRead, IsTerminal, Write};
+
+use biome_configuration::Configuration;
+use clap::ValueEnum;
+
+use crate::eslint::enabled_rules;
+use crate::hints::unmapped_hint;
+use crate::mapping::Mapping;
+use crate::options::{CssTarget, EjectOptions, Target};
+use crate::registry::{RuleOrigin, RuleRegistry};
+
+/// Whether the questions can be answered, which requires stdin to be a terminal.
+pub fn is_available() -> bool {
+    io::stdin().is_terminal()
+}
+
+/// Questions asked on the output and answered from the input, i.e. stderr and stdin of the
+/// terminal.
+pub struct Prompt<R, W> {
+    input: R,
+    output: W,
+}
+
+impl Prompt<io::StdinLock<'static>, io::Stderr> {
+    pub fn terminal() -> Self {
+        Self::new(io::stdin().lock(), io::stderr())
+    }
+}
+
+impl<R: BufRead, W: Write> Prompt<R, W> {
+    pub fn new(input: R, output: W) -> Self {
+        Self { input, output }
+    }
+
+    /// Asks the question, returning the trimmed answer, or an empty one at the end of the input.
+    fn ask(&mut self, question: &str) -> String {
+        write!(self.output, "{question} ").unwrap();
+        self.output.flush().unwrap();
+
+        let mut answer = String::new();
+        self.input.read_line(&mut answer).unwrap();
+
+        answer.trim().to_string()
+    }
+
+    fn confirm(&mut self, question: &str, default: bool) -> bool {
+        let hint = if default { "[Y/n]" } else { "[y/N]" };
+
+        loop {
+            match self
+                .ask(&format!("{question} {hint}"))
+                .to_ascii_lowercase()
+                .as_str()
+            {
+                "" => return default,
+                "y" | "yes" => return true,
+                "n" | "no" => return false,
+                _ => continue,
+            }
+        }
+    }
+
+    /// Asks the options, overwriting the ones given by the flags.
+    pub fn options(&mut self, options: &mut EjectOptions) {
+        // Reporting the rules to migrate back is done before asking anything
+        let names: Vec<_> = Target::value_variants()
+            .iter()
+            .filter(|target| **target != Target::BiomeMigrate)
+            .filter_map(|target| target.to_possible_value())
+            .map(|value| value.get_name().to_string())
+            .collect();
+
+        options.targets = loop {
+            let answer = self.ask(&format!(
+                "Generate which, separated by commas? [{}]",
+                names.join(",")
+            ));
+            if answer.is_empty() {
+                break options.targets.clone();
+            }
+
+            let targets: Result<Vec<_>, _> = answer
+                .split(',')
+                .map(|name| Target::from_str(name.trim(), true))
+                .collect();
+
+            if let Ok(targets) = targets
+                && !targets.contains(&Target::BiomeMigrate)
+            {
+                break targets;
+            }
+        };
+
+        options.css_target = loop {
+            let answer = self.ask("Eject the CSS rules into? [none/eslint/stylelint]");
+            if answer.is_empty() || answer == "none" {
+                break None;
+            }
+
+            if let Ok(target) = CssTarget::from_str(&answer, true) {
+                break Some(target);
+            }
+        };
+
+        options.skip_nursery =
+            self.confirm("Skip the rules in the nursery group?", options.skip_nursery);
+        options.prefer_stylistic = self.confirm(
+            "Rewrite the deprecated ESLint core rules to @stylistic?",
+            options.prefer_stylistic,
+        );
+    }
+
+    /// Asks the ESLint rules to translate the enabled rules without any equivalent into.
+    ///
+    /// The answers are returned as a mapping, so they are ejected the same as the `--mapping` file.
+    pub fn mapping(
+        &mut self,
+        registry: &RuleRegistry,
+        configs: &[Configuration],
+        options: &EjectOptions,
+    ) -> Mapping {
+        let unmapped: BTreeSet<_> = configs
+            .iter()
+            .flat_map(|config| enabled_rules(registry, &config.get_linter_rules()))
+            .filter(|(group, _)| !(options.skip_nursery && *group == "nursery"))
+            .filter(|(group, rule)| {
+                registry
+                    .groups
+                    .get(group)
+                    .and_then(|rules| rules.get(rule))
+                    .is_some_and(|info| info.sources.is_empty())
+            })
+            .collect();
+
+        let mut mapping = Mapping::default();
+
+        for (group, rule) in unmapped {
+            let question = match unmapped_hint(rule) {
+                Some(hint) => format!(
+                    "{group}/{rule} has no ESLint equivalent (consider {hint}). Rule to map to, or empty to skip:"
+                ),
+                None => format!(
+                    "{group}/{rule} has no ESLint equivalent. Rule to map to, or empty to skip:"
+                ),
+            };
+
+            let answer = self.ask(&question);
+            if !answer.is_empty() {
+                mapping.insert(group, rule, RuleOrigin::from_namespaced_rule_name(&answer));
+            }
+        }
+
+        mapping
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use biome_diagnostics::Severity;
+
+    use super::*;
+    use crate::eslint::RuleSourceKind;
+    use crate::testing::{config, options, registry};
+
+    #[test]
+    fn options_are_answered() {
+        let mut output = Vec::new();
+        let mut prompt = Prompt::new(&b"eslint,prettier\nstylelint\ny\nn\n"[..], &mut output);
+
+        let mut options = options();
+        prompt.options(&mut options);
+
+        assert_eq!(options.targets, [Target::Eslint, Target::Prettier]);
+        assert_eq!(options.css_target, Some(CssTarget::Stylelint));
+        assert!(options.skip_nursery);
+        assert!(!options.prefer_stylistic);
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(
+            output.starts_with("Generate which, separated by commas? [eslint,eslintrc,prettier]")
+        );
+    }
+
+    #[test]
+    fn invalid_targets_are_asked_again() {
+        let mut prompt = Prompt::new(&b"vite\nbiome-migrate\neslintrc\n"[..], Vec::new());
+
+        let mut options = options();
+        prompt.options(&mut options);
+
+        assert_eq!(options.targets, [Target::Eslintrc]);
+    }
+
+    #[test]
+    fn defaults_are_kept_at_the_end_of_the_input() {
+        let mut prompt = Prompt::new(&b""[..], Vec::new());
+
+        let mut options = options();
+        prompt.options(&mut options);
+
+        assert_eq!(options.targets, [Target::Eslint]);
+        assert_eq!(options.css_target, None);
+        assert!(!options.skip_nursery);
+    }
+
+    #[test]
+    fn unmapped_rules_are_answered() {
+        let registry = registry(&[("complexity", "noForEach", Severity::Warning, &[])]);
+        let config =
+            config(r#"{ "linter": { "rules": { "complexity": { "noForEach": "warn" } } } }"#);
+
+        let mut prompt = Prompt::new(&b"unicorn/no-array-for-each\n"[..], Vec::new());
+        let mapping = prompt.mapping(&registry, &[config], &options());
+
+        let (rule, origin) = mapping.iter().next().unwrap();
+        assert_eq!(*rule, ("complexity", "noForEach"));
+        assert_eq!(origin.kind, RuleSourceKind::EslintUnicorn);
+    }
+}
@@ -0,0 +1,83 @@
+use biome_diagnostics::Severity;
+use serde_json::{Value, json};
+
+use crate::eslint::EslintLevel;
+
+/// Encodes the levels of the rules into the vocabulary of a target linter.
+pub trait LevelCodec {
+    fn encode(&self, level: EslintLevel) -> Value;
+}
+
+/// `"off"`, `"warn"` and `"error"` of ESLint.
+pub struct EslintCodec;
+
+impl LevelCodec for EslintCodec {
+    fn encode(&self, level: EslintLevel) -> Value {
+        Value::String(level.as_str().to_string())
+    }
+}
+
+/// `0`, `1` and `2` of ESLint, preferred by some of the users and tools.
+pub struct NumericCodec;
+
+impl LevelCodec for NumericCodec {
+    fn encode(&self, level: EslintLevel) -> Value {
+        match level {
+            EslintLevel::Off => json!(0),
+            EslintLevel::Warn => json!(1),
+            EslintLevel::Error => json!(2),
+        }
+    }
+}
+
+/// Codec of the levels written into the ESLint configs.
+pub fn eslint_codec(numeric: bool) -> &'static dyn LevelCodec {
+    if numeric { &NumericCodec } else { &EslintCodec }
+}
+
+/// `null`, `[true, { "severity": "warning" }]` and `true` of Stylelint.
+pub struct StylelintCodec;
+
+impl LevelCodec for StylelintCodec {
+    fn encode(&self, level: EslintLevel) -> Value {
+        match level {
+            EslintLevel::Off => Value::Null,
+            EslintLevel::Warn => json!([true, { "severity": "warning" }]),
+            EslintLevel::Error => Value::Bool(true),
+        }
+    }
+}
+
+/// Levels of ESLint to eject each severity of Biome at.
+///
+/// ESLint has no levels for the information and hints, which are downgraded to warnings by
+/// default.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LevelMapping {
+    pub error: EslintLevel,
+    pub warning: EslintLevel,
+    pub information: EslintLevel,
+    pub hint: EslintLevel,
+}
+
+impl Default for LevelMapping {
+    fn default() -> Self {
+        Self {
+            error: EslintLevel::Error,
+            warning: EslintLevel::Warn,
+            information: EslintLevel::Warn,
+            hint: EslintLevel::Warn,
+        }
+    }
+}
+
+impl LevelMapping {
+    pub fn level(&self, severity: Severity) -> EslintLevel {
+        match severity {
+            Severity::Error | Severity::Fatal => self.error,
+            Severity::Warning => self.warning,
+            Severity::Information => self.information,
+            Severity::Hint => self.hint,
+        }
+    }
+}
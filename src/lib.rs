@@ -0,0 +1,64 @@
+//! Ejects the Biome config into the configs of ESLint, Prettier, and Stylelint.
+//!
+//! The CLI is a thin wrapper around the modules below, which can also be embedded in the other
+//! tools, e.g. [`eject_eslint`] to build the flat config in memory, or
+//! [`package::eject_packages`] to eject the packages the same as the CLI.
+//!
+//! The errors are returned as [`Error`] rather than exiting the process, leaving them to the
+//! embedders to report.
+
+pub mod assist;
+pub mod cache;
+pub mod config;
+pub mod dependencies;
+pub mod deprecated;
+pub mod diagnostics;
+pub mod eslint;
+pub mod eslintrc;
+pub mod hints;
+pub mod ignores;
+pub mod interactive;
+pub mod levels;
+pub mod mapping;
+pub mod migrate;
+pub mod options;
+pub mod output;
+pub mod overrides;
+pub mod package;
+pub mod presets;
+pub mod prettier;
+pub mod registry;
+pub mod report;
+pub mod resolve;
+pub mod rule_options;
+pub mod scopes;
+pub mod scripts;
+pub mod stylelint;
+
+#[cfg(test)]
+mod testing;
+
+use biome_configuration::Configuration;
+
+pub use crate::diagnostics::Error;
+pub use crate::eslint::RuleSourceKind;
+pub use crate::registry::RuleRegistry;
+
+/// Builds the ESLint flat config from the Biome config, with the options of the CLI by default.
+///
+/// The rules of the analyzers are visited only on the first call, shared by the later ones.
+#[cfg(feature = "registry")]
+pub fn eject_eslint(config: &Configuration) -> Result<String, Error> {
+    eject_eslint_with(RuleRegistry::shared(), config)
+}
+
+/// Builds the ESLint flat config from the Biome config with the registry, e.g. one the mapping is
+/// applied to.
+pub fn eject_eslint_with(registry: &RuleRegistry, config: &Configuration) -> Result<String, Error> {
+    let options = options::EjectOptions {
+        output: "eslint.config.mjs".into(),
+        ..Default::default()
+    };
+
+    Ok(eslint::build_eslint_config(registry, config, &options)?.config)
+}
@@ -1,58 +1,541 @@
-mod eslint;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 
-use std::collections::BTreeMap;
-use std::fs::File;
+use clap::Parser;
 
-use biome_analyze::{Queryable, RegistryVisitor, Rule, RuleGroup, RuleMetadata};
-use biome_configuration::Configuration;
-use biome_js_analyze::visit_registry;
-use biome_js_syntax::JsLanguage;
+use biome_eject::diagnostics::{Level, fail, warning};
+use biome_eject::eslint::EslintLevel;
+use biome_eject::mapping::Mapping;
+use biome_eject::migrate::build_biome_migration_report;
+use biome_eject::options::{
+    CssTarget, DryRun, EjectOptions, EslintrcFormat, GitignoreMode, LineEnding, Only, Profile,
+    RuleNameStyle, SourceSelection, Target, WarningFormat,
+};
+use biome_eject::output::{OutputFile, print_contents, print_dry_run};
+use biome_eject::package::{check_packages, eject_packages, load_config};
+use biome_eject::registry::RuleRegistry;
+use biome_eject::report::write_report;
+use biome_eject::{diagnostics, interactive};
 
-use crate::eslint::write_eslint_config;
+/// Migrate your Biome config to classic ESLint stack you liked.
+#[derive(Parser)]
+#[command(version, about)]
+struct Args {
+    /// Directories of the packages to eject, each containing its own Biome config.
+    #[arg(value_name = "DIR", default_value = ".")]
+    packages: Vec<PathBuf>,
 
-type Rules = BTreeMap<&'static str, RuleMetadata>;
-type Groups = BTreeMap<&'static str, Rules>;
+    /// Biome config to eject, instead of the one in the package, e.g. packages/web/biome.json.
+    ///
+    /// The files are generated next to it.
+    #[arg(long, value_name = "PATH", conflicts_with = "packages")]
+    config: Option<PathBuf>,
 
-#[derive(Default)]
-struct RuleRegistry {
-    groups: Groups,
+    /// Path to write the ESLint flat config to, relative to the package.
+    #[arg(long, value_name = "PATH", default_value = "eslint.config.mjs")]
+    output: PathBuf,
+
+    /// Number of the packages to eject in parallel.
+    #[arg(long, value_name = "N", default_value = "1")]
+    concurrency: NonZeroUsize,
+
+    /// What to generate, separated by commas to generate several at once, e.g. eslint,eslintrc.
+    #[arg(long, value_enum, value_delimiter = ',', default_values = ["eslint", "prettier"])]
+    target: Vec<Target>,
+
+    /// Which of the tools to eject into.
+    #[arg(long, value_enum, default_value_t)]
+    only: Only,
+
+    /// Environments to declare in env of the eslintrc target, e.g. browser,node.
+    #[arg(long, value_name = "ENV", value_delimiter = ',')]
+    legacy_env: Vec<String>,
+
+    /// Syntax of the config written for the eslintrc target.
+    #[arg(long, value_enum, default_value_t)]
+    format: EslintrcFormat,
+
+    /// Preset of the options, which the flags are applied on top of.
+    #[arg(long, value_enum, default_value_t)]
+    profile: Profile,
+
+    /// Rewrite ESLint core rules deprecated in favor of @stylistic to their replacements.
+    #[arg(long)]
+    prefer_stylistic: bool,
+
+    /// Which of the ESLint rules to eject a Biome rule into, when inspired by several: first, all,
+    /// or the namespace of the plugin to prefer (e.g. @typescript-eslint, or eslint for the core).
+    #[arg(long, value_name = "SELECTION", default_value = "first")]
+    source_selection: SourceSelection,
+
+    /// Line endings of the generated files.
+    #[arg(long, value_enum, default_value_t)]
+    line_ending: LineEnding,
+
+    /// How to name the rules of the plugins. Only namespaced is supported, as ESLint resolves the
+    /// bare names against its core rules even in a config object of a single plugin.
+    #[arg(long, value_enum, value_name = "STYLE", default_value_t)]
+    rule_name_style: RuleNameStyle,
+
+    /// Suggest manual ESLint equivalents for the rules that could not be mapped.
+    #[arg(long)]
+    explain_unmapped: bool,
+
+    /// Eject the CSS rules too, into either the ESLint config or a Stylelint config.
+    #[arg(long, value_enum)]
+    css_target: Option<CssTarget>,
+
+    /// Annotate each rule with a trailing comment linking to its documentation.
+    #[arg(long)]
+    include_metadata_comment: bool,
+
+    /// Skip the rules in the nursery group, which are not stable yet.
+    ///
+    /// Overrides the profile either way, e.g. =false to turn it off.
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    skip_nursery: Option<bool>,
+
+    /// Omit the rules turned off explicitly, instead of configuring them as off.
+    #[arg(long)]
+    skip_off: bool,
+
+    /// Emit the rules in the nursery group into a config object of their own, marked experimental.
+    #[arg(long, conflicts_with = "skip_nursery")]
+    isolate_nursery: bool,
+
+    /// Skip the rules of the plugins, ejecting only the ESLint core rules.
+    ///
+    /// Overrides the profile either way, e.g. =false to turn it off.
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    core_only: Option<bool>,
+
+    /// Emit the rules reporting warnings as errors.
+    ///
+    /// Overrides the profile either way, e.g. =false to turn it off.
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    promote_warnings: Option<bool>,
+
+    /// Fail if any enabled rule has no ESLint equivalent.
+    ///
+    /// Overrides the profile either way, e.g. =false to turn it off.
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    fail_on_unmapped: Option<bool>,
+
+    /// Level to eject the rules at the info severity at, which ESLint has no level for.
+    #[arg(long, value_enum, value_name = "LEVEL")]
+    info_as: Option<EslintLevel>,
+
+    /// Fail if any enabled rule is at the info severity, which ESLint has no level for.
+    #[arg(long)]
+    strict_severity_parity: bool,
+
+    /// Turn off every other rule of the plugin registered under the namespace, e.g. @typescript-eslint.
+    #[arg(long, value_name = "NAMESPACE")]
+    explicit_off_complement: Vec<String>,
+
+    /// Extend js.configs.recommended of @eslint/js, configuring only the core rules deviating from it.
+    #[arg(long)]
+    extend_js_recommended: bool,
+
+    /// Scope the rules of the TypeScript and JSX plugins to the files of their extensions.
+    #[arg(long)]
+    scope_by_extension: bool,
+
+    /// Eject only the rules configured with a severity other than their default in Biome.
+    #[arg(long)]
+    only_deviations: bool,
+
+    /// Emit the levels of the rules always in the array form, e.g. ["error"].
+    #[arg(long)]
+    uniform_array_levels: bool,
+
+    /// Emit the levels of the rules as the numbers, e.g. 2 instead of "error".
+    #[arg(long)]
+    numeric_levels: bool,
+
+    /// Do not add the `import/resolver` settings for the rules of eslint-plugin-import.
+    #[arg(long)]
+    no_import_resolver: bool,
+
+    /// Emit the global ignores with globalIgnores() of @eslint/config-helpers.
+    #[arg(long)]
+    flat_config_helpers: bool,
+
+    /// How to ignore the files in the .gitignore respected by Biome.
+    #[arg(long, value_enum, default_value_t)]
+    gitignore: GitignoreMode,
+
+    /// Local flat config to extend, spread before the generated config objects.
+    ///
+    /// The path is relative to each package, and imported relatively from eslint.config.mjs.
+    #[arg(long, value_name = "PATH")]
+    base_config: Option<PathBuf>,
+
+    /// Write the overrides into eslint.overrides.mjs, spread into eslint.config.mjs.
+    #[arg(long)]
+    split_overrides: bool,
+
+    /// Replace the lint and format scripts running Biome in package.json.
+    #[arg(long)]
+    update_scripts: bool,
+
+    /// Add the packages imported from the generated config to devDependencies in package.json.
+    #[arg(long)]
+    install_plugins: bool,
+
+    /// Warn about the packages imported from the generated config missing in node_modules.
+    #[arg(long)]
+    check_resolve: bool,
+
+    /// Write the coverage of the rules in JSON to the file, or to stdout if - or omitted.
+    ///
+    /// The progress and the summary are written to stderr, not to be mixed with the report.
+    #[arg(long, value_name = "PATH", num_args = 0..=1, default_missing_value = "-")]
+    report: Option<PathBuf>,
+
+    /// Archive the Biome config the files are ejected from into .biome-eject/biome.json.
+    #[arg(long)]
+    archive_source: bool,
+
+    /// Report how much of the rules can be ejected, without writing any files.
+    #[arg(long, conflicts_with_all = ["dry_run", "check"])]
+    summary_only: bool,
+
+    /// Write eslint.config.d.ts declaring the type of the config next to it.
+    #[arg(long)]
+    emit_types: bool,
+
+    /// Exit with the failure if the generated files are not up to date, without writing them.
+    #[arg(long, conflicts_with = "dry_run")]
+    check: bool,
+
+    /// Format of the warnings and the errors, detecting GitHub Actions by default.
+    #[arg(long, value_enum)]
+    format_warnings: Option<WarningFormat>,
+
+    /// Print the files to stdout instead of writing them, as the diff against the filesystem by
+    /// default.
+    #[arg(long, value_enum, value_name = "MODE", num_args = 0..=1, default_missing_value = "diff")]
+    dry_run: Option<DryRun>,
+
+    /// Log how each rule is resolved to stderr.
+    #[arg(short, long)]
+    verbose: bool,
+
+    /// Ask the options and the rules to map the unmapped rules to, if stdin is a terminal.
+    #[arg(long)]
+    interactive: bool,
+
+    /// JSON file translating Biome rules into ESLint rules, e.g. {"style/useConst": "prefer-const"}.
+    #[arg(long, value_name = "PATH", required = cfg!(not(feature = "registry")))]
+    mapping: Option<PathBuf>,
+}
+
+impl Args {
+    /// Checks the combination of the arguments, returning why it is not supported if so.
+    fn validate(&self) -> Result<(), &'static str> {
+        // The report would be mixed with the files printed, not to be parsed anymore
+        if self.dry_run.is_some() && self.prints_report() {
+            return Err(
+                "--dry-run cannot be combined with --report printed to stdout, pass a path to --report",
+            );
+        }
+
+        // The config would fail to load with the rules of the plugins unprefixed
+        if self.rule_name_style == RuleNameStyle::Bare {
+            return Err(
+                "--rule-name-style bare is not supported, as ESLint resolves the rules without the namespace against its core rules only, even in a config object registering a single plugin",
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Whether the report is printed to stdout, where nothing else may be printed along with it.
+    fn prints_report(&self) -> bool {
+        self.report.as_deref() == Some(Path::new("-"))
+    }
+
+    /// Directories of the packages to eject, which is the one containing `--config` if passed, as
+    /// the files are generated next to it.
+    fn package_dirs(&self) -> Vec<PathBuf> {
+        match &self.config {
+            Some(config) => {
+                let dir = config.parent().filter(|dir| !dir.as_os_str().is_empty());
+                vec![dir.unwrap_or(Path::new(".")).to_path_buf()]
+            }
+            None => self.packages.clone(),
+        }
+    }
 }
 
-impl RegistryVisitor<JsLanguage> for RuleRegistry {
-    fn record_rule<R>(&mut self)
-    where
-        R: Rule<Query: Queryable<Language = JsLanguage, Output: Clone>> + 'static,
-    {
-        let group = R::Group::NAME;
-        let metadata = R::METADATA;
+impl From<&Args> for EjectOptions {
+    fn from(args: &Args) -> Self {
+        let mut options = Self {
+            line_ending: args.line_ending,
+            targets: args.target.clone(),
+            only: args.only,
+            config_file: args.config.clone(),
+            output: args.output.clone(),
+            source_selection: args.source_selection.clone(),
+            legacy_env: args.legacy_env.clone(),
+            base_config: args.base_config.clone(),
+            gitignore: args.gitignore,
+            format: args.format,
+            css_target: args.css_target,
+            explicit_off_complement: args.explicit_off_complement.clone(),
+            ..Default::default()
+        };
+
+        args.profile.apply(&mut options);
+
+        // The options bundled in the profiles are overridden by the flags either way
+        for (option, flag) in [
+            (&mut options.skip_nursery, args.skip_nursery),
+            (&mut options.core_only, args.core_only),
+            (&mut options.promote_warnings, args.promote_warnings),
+            (&mut options.fail_on_unmapped, args.fail_on_unmapped),
+        ] {
+            if let Some(flag) = flag {
+                *option = flag;
+            }
+        }
 
-        self.groups
-            .entry(group)
-            .or_insert_with(Default::default)
-            .insert(metadata.name, metadata);
+        // The rest can only be turned on, as no profile bundles them
+        options.prefer_stylistic |= args.prefer_stylistic;
+        options.explain_unmapped |= args.explain_unmapped;
+        options.include_metadata_comment |= args.include_metadata_comment;
+        options.skip_off |= args.skip_off;
+        options.isolate_nursery |= args.isolate_nursery;
+        options.strict_severity_parity |= args.strict_severity_parity;
+        options.extend_js_recommended |= args.extend_js_recommended;
+        options.uniform_array_levels |= args.uniform_array_levels;
+        options.numeric_levels |= args.numeric_levels;
+        options.no_import_resolver |= args.no_import_resolver;
+        options.flat_config_helpers |= args.flat_config_helpers;
+        options.split_overrides |= args.split_overrides;
+        options.update_scripts |= args.update_scripts;
+        options.install_plugins |= args.install_plugins;
+        options.check_resolve |= args.check_resolve;
+        options.summary_only |= args.summary_only;
+        options.verbose |= args.verbose;
+        options.only_deviations |= args.only_deviations;
+        options.scope_by_extension |= args.scope_by_extension;
+        options.emit_types |= args.emit_types;
+        options.archive_source |= args.archive_source;
+
+        if let Some(level) = args.info_as {
+            options.level_mapping.information = level;
+        }
+
+        options
     }
 }
 
 fn main() {
-    let config = File::open("biome.json")
-        .or_else(|_| File::open("biome.jsonc"))
-        .unwrap();
+    let mut args = Args::parse();
+
+    args.packages = args.package_dirs();
 
-    let config: Configuration = serde_json::from_reader(&config).unwrap();
+    diagnostics::init(args.format_warnings);
 
+    if let Err(message) = args.validate() {
+        fail(None, format_args!("{message}"));
+    }
+
+    let mut options = EjectOptions::from(&args);
+
+    #[cfg(feature = "registry")]
+    let mut registry = RuleRegistry::visit();
+
+    // Every rule has to be translated by the mapping without the registry
+    #[cfg(not(feature = "registry"))]
     let mut registry = RuleRegistry::default();
 
-    visit_registry(&mut registry);
+    if let Some(path) = &args.mapping {
+        let mapping = Mapping::load(path).unwrap_or_else(|error| error.fail());
+        registry.apply_mapping(&mapping);
+    }
+
+    if args.target.contains(&Target::BiomeMigrate) {
+        if args.target.len() > 1 {
+            diagnostics::emit(
+                Level::Error,
+                None,
+                format_args!("--target biome-migrate cannot be combined with the other targets"),
+            );
+            std::process::exit(1);
+        }
+
+        let source = match std::fs::read_to_string(&args.output) {
+            Ok(source) => source,
+            Err(e) => fail(
+                Some(&args.output),
+                format_args!("could not read {}: {e}", args.output.display()),
+            ),
+        };
+
+        print!("{}", build_biome_migration_report(&registry, &source));
+        return;
+    }
+
+    if args.interactive {
+        if interactive::is_available() {
+            let mut prompt = interactive::Prompt::terminal();
+            prompt.options(&mut options);
+
+            let configs: Vec<_> = args
+                .packages
+                .iter()
+                .map(|dir| match load_config(dir, &options) {
+                    Ok(loaded) => loaded.config,
+                    Err(error) => error.fail(),
+                })
+                .collect();
+            registry.apply_mapping(&prompt.mapping(&registry, &configs, &options));
+        } else {
+            warning!("stdin is not a terminal, ignoring --interactive");
+        }
+    }
+
+    if args.check {
+        // The mapping is loaded from outside of the package, so it cannot be fingerprinted
+        let use_cache = args.mapping.is_none() && !args.interactive;
+
+        match check_packages(
+            &registry,
+            &args.packages,
+            &options,
+            args.concurrency,
+            use_cache,
+        ) {
+            Ok(true) => {}
+            Ok(false) => std::process::exit(1),
+            Err(error) => error.fail(),
+        }
+
+        return;
+    }
+
+    let ejected = eject_packages(&registry, &args.packages, &options, args.concurrency)
+        .unwrap_or_else(|error| error.fail());
+
+    let (outputs, reports): (Vec<_>, Vec<_>) = ejected
+        .into_iter()
+        .map(|ejected| (ejected.outputs, ejected.report))
+        .unzip();
+
+    let reports: Vec<_> = reports.into_iter().flatten().collect();
+    if let Some(path) = &args.report {
+        write_report(&reports, path);
+    }
+
+    let outputs: Vec<_> = outputs.into_iter().flatten().collect();
+
+    match args.dry_run {
+        Some(DryRun::Diff) => print_dry_run(&outputs),
+        Some(DryRun::Contents) => print_contents(&outputs),
+        None => outputs.iter().for_each(OutputFile::write),
+    }
+
+    for report in &reports {
+        eprint!("{}:\n{}", report.dir.display(), report.summary());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use biome_eject::levels::LevelMapping;
+
+    use super::*;
+
+    fn parse(flags: &[&str]) -> Args {
+        // The mapping is only parsed here, never loaded
+        let mapping = cfg!(not(feature = "registry")).then_some(["--mapping", "mapping.json"]);
+
+        Args::parse_from(
+            ["biome-eject"]
+                .into_iter()
+                .chain(mapping.into_iter().flatten())
+                .chain(flags.iter().copied()),
+        )
+    }
+
+    #[test]
+    fn flags_turn_on_the_options_on_top_of_the_profile() {
+        let options = EjectOptions::from(&parse(&["--profile", "minimal", "--promote-warnings"]));
 
-    if config.is_linter_enabled() {
-        write_eslint_config(&registry, &config);
+        assert!(options.core_only);
+        assert!(options.promote_warnings);
+    }
+
+    #[test]
+    fn flags_turn_off_the_options_of_the_profile() {
+        let options =
+            EjectOptions::from(&parse(&["--profile", "strict", "--fail-on-unmapped=false"]));
 
-        // TODO: Install plugins automatically?
+        assert!(options.promote_warnings);
+        assert!(!options.fail_on_unmapped);
     }
 
-    // TODO: Support Prettier
+    #[test]
+    fn info_as_overrides_the_profile() {
+        let options = EjectOptions::from(&parse(&["--profile", "minimal", "--info-as", "warn"]));
+
+        assert_eq!(options.level_mapping.information, EslintLevel::Warn);
+
+        let options = EjectOptions::from(&parse(&["--info-as", "error"]));
+        assert_eq!(
+            options.level_mapping,
+            LevelMapping {
+                information: EslintLevel::Error,
+                ..LevelMapping::default()
+            }
+        );
+    }
+
+    #[test]
+    fn report_is_printed_to_stdout_without_path() {
+        assert!(parse(&["--report", "--dry-run"]).prints_report());
+        assert!(parse(&["--report", "-"]).prints_report());
+        assert!(!parse(&["--report", "report.json"]).prints_report());
+        assert!(!parse(&[]).prints_report());
+    }
 
-    // TODO: Support overrides
+    #[test]
+    fn files_are_generated_next_to_the_config() {
+        let args = parse(&["--config", "packages/web/biome.json"]);
+        assert_eq!(args.package_dirs(), [PathBuf::from("packages/web")]);
+        assert_eq!(
+            EjectOptions::from(&args).config_file,
+            Some(PathBuf::from("packages/web/biome.json"))
+        );
 
-    // TODO: Uninstall Biome?
+        let args = parse(&["--config", "biome.json"]);
+        assert_eq!(args.package_dirs(), [PathBuf::from(".")]);
+
+        // Searched in the packages without the flag
+        let args = parse(&["packages/web", "packages/api"]);
+        assert_eq!(
+            args.package_dirs(),
+            [PathBuf::from("packages/web"), PathBuf::from("packages/api")]
+        );
+        assert_eq!(EjectOptions::from(&args).config_file, None);
+    }
+
+    #[test]
+    fn bare_rule_names_are_rejected() {
+        assert_eq!(parse(&[]).rule_name_style, RuleNameStyle::Namespaced);
+        assert_eq!(
+            parse(&["--rule-name-style", "namespaced"]).validate(),
+            Ok(())
+        );
+
+        let error = parse(&["--rule-name-style", "bare"])
+            .validate()
+            .unwrap_err();
+        assert!(error.starts_with("--rule-name-style bare is not supported"));
+
+        assert!(parse(&["--report", "--dry-run"]).validate().is_err());
+    }
 }
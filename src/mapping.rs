@@ -0,0 +1,148 @@
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::path::Path;
+
+use crate::diagnostics::{Error, warning};
+use crate::registry::RuleOrigin;
+
+/// User-supplied translations of Biome rules, keyed by `group/rule`.
+///
+/// ```json
+/// { "complexity/noForEach": "unicorn/no-array-for-each" }
+/// ```
+#[derive(Default)]
+pub struct Mapping {
+    rules: BTreeMap<(&'static str, &'static str), RuleOrigin>,
+}
+
+impl Mapping {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let file = File::open(path).map_err(|e| {
+            Error::new(
+                Some(path),
+                format_args!("could not read {}: {e}", path.display()),
+            )
+        })?;
+
+        let entries: BTreeMap<String, String> = serde_json::from_reader(&file).map_err(|e| {
+            Error::new(
+                Some(path),
+                format_args!("{} is not a valid mapping: {e}", path.display()),
+            )
+        })?;
+
+        let mut rules = BTreeMap::new();
+
+        for (key, eslint_rule) in entries {
+            let Some((group, rule)) = key.split_once('/') else {
+                warning!("ignoring the mapping for {key}, expected to be group/rule");
+                continue;
+            };
+
+            // The registry refers the rule names statically, and the mapping lives until the end
+            // of the process anyway.
+            let group: &'static str = group.to_string().leak();
+            let rule: &'static str = rule.to_string().leak();
+
+            rules.insert(
+                (group, rule),
+                RuleOrigin::from_namespaced_rule_name(&eslint_rule),
+            );
+        }
+
+        Ok(Self { rules })
+    }
+
+    pub fn insert(&mut self, group: &'static str, rule: &'static str, origin: RuleOrigin) {
+        self.rules.insert((group, rule), origin);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&(&'static str, &'static str), &RuleOrigin)> {
+        self.rules.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::eslint::build_eslint_config;
+    use crate::registry::RuleRegistry;
+    use crate::testing::{config, options, temp_dir};
+
+    /// Loads the mapping into an empty registry, as the builds without the registry do.
+    ///
+    /// The mapping is written into the directory named after the test, not to race with the others.
+    fn mapped_registry(test: &str, mapping: &str) -> RuleRegistry {
+        let path = temp_dir(&format!("mapping-{test}")).join("mapping.json");
+        fs::write(&path, mapping).unwrap();
+
+        let mut registry = RuleRegistry::default();
+        registry.apply_mapping(&Mapping::load(&path).unwrap());
+        registry
+    }
+
+    #[test]
+    fn rules_are_translated_by_the_mapping_only() {
+        let registry = mapped_registry(
+            "rules_are_translated_by_the_mapping_only",
+            r#"{ "style/useConst": "prefer-const", "suspicious/noDebugger": "no-debugger" }"#,
+        );
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": {
+                        "recommended": false,
+                        "style": { "useConst": "error" },
+                        "suspicious": { "noDebugger": "warn" }
+                    }
+                }
+            }"#,
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .config;
+        assert!(eslint_config.contains(r#""prefer-const": "error""#));
+        assert!(eslint_config.contains(r#""no-debugger": "warn""#));
+    }
+
+    #[cfg(not(feature = "registry"))]
+    #[test]
+    fn rules_missing_from_the_mapping_are_unmapped() {
+        use crate::eslint::collect_base_rules;
+
+        let registry = mapped_registry(
+            "rules_missing_from_the_mapping_are_unmapped",
+            r#"{ "style/useConst": "prefer-const" }"#,
+        );
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": {
+                        "recommended": false,
+                        "style": { "useConst": "error", "useImportType": "error" }
+                    }
+                }
+            }"#,
+        );
+
+        let base_rules = collect_base_rules(&registry, &config, &options());
+        assert!(base_rules.mapped.contains("style/useConst"));
+        assert!(base_rules.unmapped.contains("style/useImportType"));
+    }
+
+    #[test]
+    fn errors_of_the_mapping_are_returned() {
+        let dir = temp_dir("mapping-errors");
+
+        let error = Mapping::load(&dir.join("mapping.json")).err().unwrap();
+        assert_eq!(error.file, Some(dir.join("mapping.json")));
+        assert!(error.message.starts_with("could not read"));
+
+        fs::write(dir.join("mapping.json"), "[]").unwrap();
+        let error = Mapping::load(&dir.join("mapping.json")).err().unwrap();
+        assert!(error.message.contains("is not a valid mapping"));
+    }
+}
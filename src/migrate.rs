@@ -0,0 +1,145 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use biome_js_parser::{JsParserOptions, parse_module};
+use biome_js_syntax::{AnyJsExpression, AnyJsObjectMember, JsPropertyObjectMember};
+use biome_rowan::AstNode;
+
+use crate::registry::RuleRegistry;
+
+fn unquote(text: &str) -> &str {
+    text.trim_matches(|c| c == '"' || c == '\'')
+}
+
+/// Collects the rules configured in every `rules` object of the flat config, with their levels.
+fn collect_configured_rules(source: &str) -> BTreeMap<String, String> {
+    let module = parse_module(source, JsParserOptions::default()).tree();
+    let mut rules = BTreeMap::new();
+
+    for member in module
+        .syntax()
+        .descendants()
+        .filter_map(JsPropertyObjectMember::cast)
+    {
+        let Ok(name) = member.name() else {
+            continue;
+        };
+
+        if unquote(&name.syntax().text_trimmed().to_string()) != "rules" {
+            continue;
+        }
+
+        let Ok(AnyJsExpression::JsObjectExpression(object)) = member.value() else {
+            continue;
+        };
+
+        for rule in object.members().iter().flatten() {
+            let AnyJsObjectMember::JsPropertyObjectMember(rule) = rule else {
+                continue;
+            };
+
+            let (Ok(name), Ok(value)) = (rule.name(), rule.value()) else {
+                continue;
+            };
+
+            // ["error", { ... }] is reported by its level only
+            let value = value.syntax().text_trimmed().to_string();
+            let level = unquote(
+                value
+                    .trim_start_matches('[')
+                    .split([',', ']'])
+                    .next()
+                    .unwrap_or_default()
+                    .trim(),
+            )
+            .to_string();
+
+            rules.insert(
+                unquote(&name.syntax().text_trimmed().to_string()).to_string(),
+                level,
+            );
+        }
+    }
+
+    rules
+}
+
+/// Reports the Biome rules corresponding to the rules configured in the ESLint flat config.
+pub fn build_biome_migration_report(registry: &RuleRegistry, source: &str) -> String {
+    let mut biome_rules = BTreeMap::<&str, Vec<String>>::new();
+
+    for (group, rules) in registry.groups.iter().chain(&registry.css_groups) {
+        for (rule, info) in rules {
+            for origin in &info.sources {
+                biome_rules
+                    .entry(origin.name.as_str())
+                    .or_default()
+                    .push(format!("{group}/{rule}"));
+            }
+        }
+    }
+
+    let mut report = String::new();
+
+    for (rule, level) in collect_configured_rules(source) {
+        if level == "off" || level == "0" {
+            continue;
+        }
+
+        match biome_rules.get(rule.as_str()) {
+            Some(biome_rules) => {
+                writeln!(report, "{rule} ({level}) -> {}", biome_rules.join(", ")).unwrap()
+            }
+            None => writeln!(report, "{rule} ({level}) -> None").unwrap(),
+        }
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use biome_diagnostics::Severity;
+
+    use super::*;
+    use crate::testing::registry;
+
+    #[test]
+    fn eslint_rules_are_mapped_back_to_biome() {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            (
+                "suspicious",
+                "noExplicitAny",
+                Severity::Error,
+                &["@typescript-eslint/no-explicit-any"],
+            ),
+        ]);
+        let source = r#"
+import tseslint from "typescript-eslint";
+import { defineConfig } from "eslint/config";
+
+export default defineConfig({
+    plugins: { "@typescript-eslint": tseslint },
+    rules: {
+        "no-debugger": "error",
+        "@typescript-eslint/no-explicit-any": ["warn", { fixToUnknown: true }],
+        "no-console": "off",
+        'no-var': 2,
+    },
+});
+"#;
+
+        assert_eq!(
+            build_biome_migration_report(&registry, source),
+            "@typescript-eslint/no-explicit-any (warn) -> suspicious/noExplicitAny\n\
+             no-debugger (error) -> suspicious/noDebugger\n\
+             no-var (2) -> None\n"
+        );
+    }
+}
@@ -0,0 +1,431 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use clap::ValueEnum;
+
+use crate::eslint::{EslintLevel, RuleSourceKind};
+use crate::levels::LevelMapping;
+use crate::scopes::Scope;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum LineEnding {
+    /// Line feed only (`\n`).
+    #[default]
+    Lf,
+
+    /// Carriage return and line feed (`\r\n`).
+    Crlf,
+
+    /// Follow the `lineEnding` setting of the Biome formatter, or the OS if not set.
+    Auto,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Target {
+    /// Eject the Biome config into an ESLint flat config.
+    #[default]
+    Eslint,
+
+    /// Eject the Biome config into a legacy `.eslintrc.json` for ESLint 8.
+    Eslintrc,
+
+    /// Eject the Biome formatter settings into `.prettierrc.json`.
+    Prettier,
+
+    /// Report the Biome rules corresponding to an existing ESLint flat config, for re-adoption.
+    BiomeMigrate,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum EslintrcFormat {
+    /// Write `.eslintrc.json`.
+    #[default]
+    Json,
+
+    /// Write `.eslintrc.json5`, annotating the rules with the comments if any.
+    Json5,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum GitignoreMode {
+    /// Copy the patterns of the `.gitignore` into the config.
+    #[default]
+    Inline,
+
+    /// Read the `.gitignore` at runtime with `includeIgnoreFile()` of `@eslint/compat`.
+    Runtime,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+pub enum CssTarget {
+    /// Scope the CSS rules into the ESLint config using `@eslint/css`.
+    Eslint,
+
+    /// Write the CSS rules into a separate Stylelint config.
+    Stylelint,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Only {
+    /// Eject both the linter and the formatter, each only if enabled in Biome.
+    #[default]
+    All,
+
+    /// Eject the linter only.
+    Eslint,
+
+    /// Eject the formatter only.
+    Prettier,
+}
+
+impl Only {
+    pub fn includes_linter(&self) -> bool {
+        matches!(self, Self::All | Self::Eslint)
+    }
+
+    pub fn includes_formatter(&self) -> bool {
+        matches!(self, Self::All | Self::Prettier)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum DryRun {
+    /// Print the diff of the files against the filesystem.
+    #[default]
+    Diff,
+
+    /// Print the generated files as-is, e.g. to pipe into another tool.
+    Contents,
+}
+
+/// How the rules of the plugins are named in the flat config.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum RuleNameStyle {
+    /// Prefix the rules with the namespace of their plugin, e.g. `@typescript-eslint/no-explicit-any`.
+    #[default]
+    Namespaced,
+
+    /// Not supported, as flat config resolves the rules without any prefix against the core rules
+    /// only, even in a config object registering a single plugin.
+    Bare,
+}
+
+/// Which of the ESLint rules to eject a Biome rule into, when it is inspired by several.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub enum SourceSelection {
+    /// Only the first one in the metadata of the rule.
+    #[default]
+    First,
+
+    /// Every one of them.
+    All,
+
+    /// Only the one of the plugin registered under the namespace, e.g. `@typescript-eslint`, where
+    /// `eslint` stands for the core rules.
+    ///
+    /// Falls back to the first one if none of them is of the plugin.
+    PreferNamespace(String),
+}
+
+impl FromStr for SourceSelection {
+    type Err = String;
+
+    /// Parses `first`, `all`, or the namespace of a plugin known to the tool, e.g.
+    /// `@typescript-eslint`, or `eslint` for the core rules.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "first" => Self::First,
+            "all" => Self::All,
+            "eslint" => Self::PreferNamespace(s.to_string()),
+            namespace if RuleSourceKind::from_namespace(namespace).is_some() => {
+                Self::PreferNamespace(namespace.to_string())
+            }
+            _ => {
+                return Err(format!(
+                    "expected first, all, eslint, or the namespace of a known plugin, got `{s}`"
+                ));
+            }
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum WarningFormat {
+    /// `warning: ...` for humans.
+    #[default]
+    Text,
+
+    /// `::warning::...` workflow commands, annotating the pull requests on GitHub Actions.
+    Github,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Profile {
+    /// Promote the warnings to errors, and fail if any enabled rule cannot be mapped.
+    Strict,
+
+    /// Eject the rules as configured in Biome.
+    #[default]
+    Recommended,
+
+    /// Eject only the stable ESLint core rules, without any plugins nor the rules at the info
+    /// severity.
+    Minimal,
+}
+
+impl Profile {
+    /// Sets the options bundled in the profile, on top of which the flags are applied.
+    pub fn apply(&self, options: &mut EjectOptions) {
+        match self {
+            Self::Strict => {
+                options.promote_warnings = true;
+                options.fail_on_unmapped = true;
+            }
+            Self::Recommended => {}
+            Self::Minimal => {
+                options.core_only = true;
+                options.skip_nursery = true;
+                options.level_mapping.information = EslintLevel::Off;
+                options.level_mapping.hint = EslintLevel::Off;
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct EjectOptions {
+    /// What to generate, all sharing the visit of the registry and the parse of the Biome config.
+    pub targets: Vec<Target>,
+
+    /// Which of the tools to eject into.
+    pub only: Only,
+
+    /// Biome config to eject, instead of `biome.json` or `biome.jsonc` in the root.
+    pub config_file: Option<PathBuf>,
+
+    /// Path to write the ESLint flat config to, relative to the root.
+    pub output: PathBuf,
+
+    /// Environments to declare in `env` of the eslintrc target, e.g. `browser`.
+    pub legacy_env: Vec<String>,
+
+    /// Syntax of the config written for the eslintrc target.
+    pub format: EslintrcFormat,
+
+    /// Rewrite deprecated ESLint core rules to their `@stylistic` replacements.
+    pub prefer_stylistic: bool,
+
+    /// Which of the ESLint rules to eject a Biome rule into, when it is inspired by several.
+    pub source_selection: SourceSelection,
+
+    /// Line endings of the generated files.
+    pub line_ending: LineEnding,
+
+    /// Suggest manual ESLint equivalents for rules without an ESLint source.
+    pub explain_unmapped: bool,
+
+    /// Where to eject the CSS rules into, or skip them if not set.
+    pub css_target: Option<CssTarget>,
+
+    /// Annotate each rule with a trailing comment linking to its documentation.
+    pub include_metadata_comment: bool,
+
+    /// Skip the rules in the nursery group, which are not stable yet.
+    pub skip_nursery: bool,
+
+    /// Omit the rules turned off explicitly, instead of configuring them as `off`.
+    pub skip_off: bool,
+
+    /// Emit the rules in the nursery group into a config object of their own.
+    pub isolate_nursery: bool,
+
+    /// Skip the rules of the plugins, ejecting only the ESLint core rules.
+    pub core_only: bool,
+
+    /// Emit the rules reporting warnings as errors.
+    pub promote_warnings: bool,
+
+    /// Fail if any enabled rule has no ESLint equivalent.
+    pub fail_on_unmapped: bool,
+
+    /// Levels of ESLint to eject each severity of Biome at.
+    pub level_mapping: LevelMapping,
+
+    /// Fail if any enabled rule is at a severity ESLint cannot represent, instead of downgrading.
+    pub strict_severity_parity: bool,
+
+    /// Namespaces of the plugins to turn off the rules not enabled explicitly, e.g. `@typescript-eslint`.
+    pub explicit_off_complement: Vec<String>,
+
+    /// Extend `js.configs.recommended` of `@eslint/js`, configuring only the core rules deviating
+    /// from it.
+    pub extend_js_recommended: bool,
+
+    /// Eject only the rules configured with a severity other than their default in Biome.
+    pub only_deviations: bool,
+
+    /// Emit the levels of the rules always in the array form, e.g. `["error"]`.
+    pub uniform_array_levels: bool,
+
+    /// Emit the levels of the rules as the numbers, e.g. `2` instead of `"error"`.
+    pub numeric_levels: bool,
+
+    /// Omit the `import/resolver` settings added for the rules of `eslint-plugin-import`.
+    pub no_import_resolver: bool,
+
+    /// Write the overrides into `eslint.overrides.mjs`, spread into the config.
+    pub split_overrides: bool,
+
+    /// Replace the `lint` and `format` scripts running Biome in the `package.json`.
+    pub update_scripts: bool,
+
+    /// Add the packages imported from the generated config to `devDependencies` in the
+    /// `package.json`.
+    pub install_plugins: bool,
+
+    /// How to ignore the files in the `.gitignore` respected by Biome.
+    pub gitignore: GitignoreMode,
+
+    /// Emit the global ignores with `globalIgnores()` of `@eslint/config-helpers`.
+    pub flat_config_helpers: bool,
+
+    /// Warn about the packages imported from the generated config missing in `node_modules`.
+    pub check_resolve: bool,
+
+    /// Local flat config to spread before the generated config objects, relative to the root.
+    pub base_config: Option<PathBuf>,
+
+    /// Scope the rules of the TypeScript and JSX plugins to the files of their extensions.
+    pub scope_by_extension: bool,
+
+    /// Config objects to configure the rules of the groups in, instead of the global one.
+    pub scopes: BTreeMap<String, Scope>,
+
+    /// Archive the parsed Biome config into `.biome-eject/biome.json`.
+    pub archive_source: bool,
+
+    /// Report the coverage of the rules only, without generating any files.
+    pub summary_only: bool,
+
+    /// Write `eslint.config.d.ts` declaring the type of the exported config.
+    pub emit_types: bool,
+
+    /// Log how each rule is resolved to stderr.
+    pub verbose: bool,
+
+    /// Directory containing the Biome config, which the paths in it are relative to.
+    pub root: PathBuf,
+}
+
+#[cfg(test)]
+mod tests {
+    use biome_diagnostics::Severity;
+
+    use super::*;
+    use crate::eslint::build_eslint_config;
+    use crate::testing::{config, options, registry};
+
+    fn profiled(profile: Profile) -> EjectOptions {
+        let mut options = options();
+        profile.apply(&mut options);
+        options
+    }
+
+    #[test]
+    fn source_selection_from_str() {
+        assert_eq!(SourceSelection::default(), SourceSelection::First);
+        assert_eq!("first".parse(), Ok(SourceSelection::First));
+        assert_eq!("all".parse(), Ok(SourceSelection::All));
+        assert_eq!(
+            "eslint".parse(),
+            Ok(SourceSelection::PreferNamespace("eslint".to_string()))
+        );
+        assert_eq!(
+            "@typescript-eslint".parse(),
+            Ok(SourceSelection::PreferNamespace(
+                "@typescript-eslint".to_string()
+            ))
+        );
+
+        assert!("frist".parse::<SourceSelection>().is_err());
+        assert!("typescript-eslint".parse::<SourceSelection>().is_err());
+    }
+
+    #[test]
+    fn strict_profile() {
+        let options = profiled(Profile::Strict);
+
+        assert!(options.promote_warnings);
+        assert!(options.fail_on_unmapped);
+        assert!(!options.core_only);
+        assert!(!options.skip_nursery);
+    }
+
+    #[test]
+    fn recommended_profile() {
+        let options = profiled(Profile::Recommended);
+
+        assert!(!options.promote_warnings);
+        assert!(!options.fail_on_unmapped);
+        assert!(!options.core_only);
+        assert!(!options.skip_nursery);
+        assert_eq!(options.level_mapping, LevelMapping::default());
+    }
+
+    #[test]
+    fn minimal_profile() {
+        let options = profiled(Profile::Minimal);
+
+        assert!(options.core_only);
+        assert!(options.skip_nursery);
+        assert_eq!(options.level_mapping.information, EslintLevel::Off);
+        assert!(!options.promote_warnings);
+    }
+
+    #[test]
+    fn minimal_profile_ejects_the_core_rules_only() {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            (
+                "suspicious",
+                "noExplicitAny",
+                Severity::Error,
+                &["@typescript-eslint/no-explicit-any"],
+            ),
+            (
+                "suspicious",
+                "noDoubleEquals",
+                Severity::Information,
+                &["eqeqeq"],
+            ),
+        ]);
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": {
+                        "suspicious": { "noDebugger": "error", "noExplicitAny": "error", "noDoubleEquals": "info" }
+                    }
+                }
+            }"#,
+        );
+
+        let recommended = build_eslint_config(&registry, &config, &profiled(Profile::Recommended))
+            .unwrap()
+            .config;
+        assert!(recommended.contains(r#""@typescript-eslint/no-explicit-any": "error""#));
+        assert!(recommended.contains("eqeqeq"));
+
+        let minimal = build_eslint_config(&registry, &config, &profiled(Profile::Minimal))
+            .unwrap()
+            .config;
+        assert!(minimal.contains(r#""no-debugger": "error""#));
+        assert!(!minimal.contains("@typescript-eslint"));
+        assert!(!minimal.contains("eqeqeq"));
+    }
+}
@@ -0,0 +1,250 @@
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+
+use biome_configuration::Configuration;
+use biome_formatter::LineEnding as BiomeLineEnding;
+use similar::TextDiff;
+
+use crate::options::LineEnding;
+
+/// Resolves the newline sequence to write, consulting the Biome formatter settings for `auto`.
+pub fn resolve_newline(line_ending: LineEnding, config: &Configuration) -> &'static str {
+    match line_ending {
+        LineEnding::Lf => "\n",
+        LineEnding::Crlf => "\r\n",
+        LineEnding::Auto => {
+            let configured = config
+                .javascript
+                .as_ref()
+                .and_then(|javascript| javascript.formatter.as_ref())
+                .and_then(|formatter| formatter.line_ending)
+                .or_else(|| {
+                    config
+                        .formatter
+                        .as_ref()
+                        .and_then(|formatter| formatter.line_ending)
+                });
+
+            match configured {
+                Some(BiomeLineEnding::Lf) => "\n",
+                Some(BiomeLineEnding::Crlf) => "\r\n",
+                Some(BiomeLineEnding::Cr) => "\r",
+                None if cfg!(windows) => "\r\n",
+                None => "\n",
+            }
+        }
+    }
+}
+
+/// Rewrites every line terminator in the code to the newline, ensuring a final newline.
+pub fn apply_newline(code: &str, newline: &str) -> String {
+    let mut output = String::with_capacity(code.len());
+
+    for line in code.lines() {
+        output.push_str(line);
+        output.push_str(newline);
+    }
+
+    output
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FileStatus {
+    Created,
+    Modified,
+    Unchanged,
+}
+
+impl Display for FileStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Created => "created",
+            Self::Modified => "modified",
+            Self::Unchanged => "unchanged",
+        })
+    }
+}
+
+/// A rendered file to be written into the filesystem.
+pub struct OutputFile {
+    pub path: PathBuf,
+    pub contents: String,
+}
+
+impl OutputFile {
+    pub fn new(path: impl Into<PathBuf>, contents: String) -> Self {
+        Self {
+            path: path.into(),
+            contents,
+        }
+    }
+
+    fn read_current(&self) -> Option<String> {
+        std::fs::read_to_string(&self.path).ok()
+    }
+
+    /// Compares the contents against the file currently on the disk.
+    pub fn status(&self) -> FileStatus {
+        match self.read_current() {
+            Some(current) if current == self.contents => FileStatus::Unchanged,
+            Some(_) => FileStatus::Modified,
+            None => FileStatus::Created,
+        }
+    }
+
+    /// Renders a unified diff from the file currently on the disk to the contents.
+    pub fn diff(&self) -> String {
+        let path = self.path.display().to_string();
+        let (current, old_path) = match self.read_current() {
+            Some(current) => (current, path.as_str()),
+            None => (String::new(), "/dev/null"),
+        };
+
+        TextDiff::from_lines(&current, &self.contents)
+            .unified_diff()
+            .header(old_path, &path)
+            .to_string()
+    }
+
+    pub fn write(&self) {
+        // Some files are written into the directories of their own, e.g. `.biome-eject/`
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).unwrap();
+        }
+
+        File::create(&self.path)
+            .unwrap()
+            .write_all(self.contents.as_bytes())
+            .unwrap();
+    }
+}
+
+/// Prints the diff of every output against the filesystem, followed by a summary of the changes.
+pub fn print_dry_run(outputs: &[OutputFile]) {
+    for output in outputs {
+        print!("{}", output.diff());
+    }
+
+    for output in outputs {
+        println!("{}: {}", output.path.display(), output.status());
+    }
+}
+
+/// Prints the contents of every output, headed by its path if there are several.
+pub fn print_contents(outputs: &[OutputFile]) {
+    for (index, output) in outputs.iter().enumerate() {
+        if outputs.len() > 1 {
+            let separator = if index > 0 { "\n" } else { "" };
+            println!("{separator}==> {} <==", output.path.display());
+        }
+
+        print!("{}", output.contents);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use biome_diagnostics::Severity;
+
+    use super::*;
+    use crate::eslint::build_eslint_config;
+    use crate::options::EjectOptions;
+    use crate::testing::{config, options, registry, temp_dir};
+
+    #[test]
+    fn newlines_are_rewritten() {
+        assert_eq!(apply_newline("a\nb\r\nc", "\r\n"), "a\r\nb\r\nc\r\n");
+        assert_eq!(apply_newline("a\n", "\n"), "a\n");
+    }
+
+    #[test]
+    fn auto_follows_the_formatter() {
+        let config = config(r#"{ "formatter": { "lineEnding": "crlf" } }"#);
+        assert_eq!(resolve_newline(LineEnding::Auto, &config), "\r\n");
+
+        let config = config(
+            r#"{ "formatter": { "lineEnding": "crlf" }, "javascript": { "formatter": { "lineEnding": "lf" } } }"#,
+        );
+        assert_eq!(resolve_newline(LineEnding::Auto, &config), "\n");
+        assert_eq!(resolve_newline(LineEnding::Crlf, &config), "\r\n");
+    }
+
+    #[test]
+    fn config_is_written_with_crlf() {
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let config =
+            config(r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#);
+        let options = EjectOptions {
+            line_ending: LineEnding::Crlf,
+            ..options()
+        };
+
+        let eslint_config = build_eslint_config(&registry, &config, &options)
+            .unwrap()
+            .config;
+        assert!(eslint_config.ends_with("\r\n"));
+        assert_eq!(
+            eslint_config.matches('\n').count(),
+            eslint_config.matches("\r\n").count()
+        );
+    }
+
+    #[test]
+    fn outputs_are_labeled_against_the_filesystem() {
+        let dir = temp_dir("dry-run");
+        std::fs::write(dir.join("unchanged.json"), "{}\n").unwrap();
+        std::fs::write(dir.join("modified.json"), "{}\n").unwrap();
+
+        let unchanged = OutputFile::new(dir.join("unchanged.json"), "{}\n".to_string());
+        let modified = OutputFile::new(dir.join("modified.json"), "[]\n".to_string());
+        let created = OutputFile::new(dir.join("created.json"), "{}\n".to_string());
+
+        assert_eq!(unchanged.status(), FileStatus::Unchanged);
+        assert_eq!(modified.status(), FileStatus::Modified);
+        assert_eq!(created.status(), FileStatus::Created);
+
+        assert!(unchanged.diff().is_empty());
+        assert!(modified.diff().contains("-{}\n+[]\n"));
+        assert!(created.diff().starts_with("--- /dev/null\n"));
+    }
+
+    #[test]
+    fn parent_directories_are_created_on_write() {
+        let dir = temp_dir("output-parent");
+        let path = dir.join("configs").join("lint").join("eslint.config.mjs");
+
+        let output = OutputFile::new(&path, "export default [];\n".to_string());
+        assert_eq!(output.status(), FileStatus::Created);
+
+        output.write();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "export default [];\n"
+        );
+        assert_eq!(output.status(), FileStatus::Unchanged);
+    }
+
+    #[test]
+    fn config_is_written_to_the_output_path() {
+        let dir = temp_dir("output-path");
+        std::fs::write(dir.join("biome.json"), "{}").unwrap();
+
+        let options = EjectOptions {
+            output: "configs/eslint.config.mjs".into(),
+            ..options()
+        };
+
+        let ejected = crate::package::eject_package(&registry(&[]), &dir, &options).unwrap();
+        assert_eq!(
+            ejected.outputs[0].path,
+            dir.join("configs").join("eslint.config.mjs")
+        );
+    }
+}
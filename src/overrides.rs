@@ -0,0 +1,307 @@
+use biome_configuration::{Configuration, Rules as RulesConfiguration};
+use serde_json::Value;
+
+use crate::diagnostics::warning;
+
+/// An entry of the `overrides` section, which reconfigures the linter rules for some files.
+pub struct Override {
+    /// Glob patterns of the files to include.
+    pub files: Vec<String>,
+
+    /// Glob patterns of the files to exclude, written as negated patterns (`!**/*.test.ts`) in Biome.
+    pub ignores: Vec<String>,
+
+    /// Rules configured for the files.
+    pub rules: RulesConfiguration,
+}
+
+/// Collects the overrides configuring any linter rules, in the order they are declared.
+pub fn collect_overrides(config: &Configuration) -> Vec<Override> {
+    let Ok(Value::Array(patterns)) = serde_json::to_value(&config.overrides) else {
+        return Vec::new();
+    };
+
+    patterns
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, pattern)| {
+            let rules = pattern.pointer("/linter/rules")?.clone();
+            let rules = match serde_json::from_value::<RulesConfiguration>(rules) {
+                Ok(rules) => rules,
+                Err(e) => {
+                    warning!("skipping an override with malformed rules: {e}");
+                    return None;
+                }
+            };
+
+            // `files: []` would silently match nothing in ESLint, the same as in Biome
+            if pattern
+                .get("includes")
+                .and_then(Value::as_array)
+                .is_some_and(Vec::is_empty)
+            {
+                warning!("skipping overrides[{index}] as its includes matches no files");
+                return None;
+            }
+
+            let mut files = Vec::new();
+            let mut ignores = Vec::new();
+            for glob in pattern
+                .get("includes")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+            {
+                match glob.strip_prefix('!') {
+                    Some(ignore) => ignores.push(ignore.to_string()),
+                    None => files.push(glob.to_string()),
+                }
+            }
+
+            Some(Override {
+                files,
+                ignores,
+                rules,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use biome_diagnostics::Severity;
+
+    use super::*;
+    use crate::eslint::build_eslint_config;
+    use crate::options::EjectOptions;
+    use crate::testing::{config, options, registry};
+
+    #[test]
+    fn overrides_resolve_the_severities_of_their_groups_and_rules() {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            ("suspicious", "noConsole", Severity::Error, &["no-console"]),
+        ]);
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": {
+                        "recommended": false,
+                        "suspicious": { "noDebugger": "error", "noConsole": "error" }
+                    }
+                },
+                "overrides": [
+                    {
+                        "includes": ["scripts/**", "!scripts/vendor/**"],
+                        "linter": { "rules": { "suspicious": "warn" } }
+                    },
+                    {
+                        "includes": ["tests/**"],
+                        "linter": { "rules": { "suspicious": { "noConsole": "off", "noDebugger": "warn" } } }
+                    }
+                ]
+            }"#,
+        );
+
+        let overrides = collect_overrides(&config);
+        assert_eq!(overrides[0].files, ["scripts/**"]);
+        assert_eq!(overrides[0].ignores, ["scripts/vendor/**"]);
+
+        let eslint_config = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .config;
+        let (_, scripts) = eslint_config
+            .split_once(r#"files: ["scripts/**"]"#)
+            .unwrap();
+        let (scripts, tests) = scripts.split_once(r#"files: ["tests/**"]"#).unwrap();
+
+        // The group-level severity applies to every rule of the group within the override
+        assert!(scripts.contains(r#"ignores: ["scripts/vendor/**"]"#));
+        assert!(scripts.contains(r#""no-debugger": "warn""#));
+        assert!(scripts.contains(r#""no-console": "warn""#));
+
+        // The rule-level severities apply to the rules only
+        assert!(tests.contains(r#""no-debugger": "warn""#));
+        assert!(tests.contains(r#""no-console": "off""#));
+    }
+
+    #[test]
+    fn split_overrides_are_spread_from_their_own_module() {
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let config = config(
+            r#"{
+                "linter": { "rules": { "suspicious": { "noDebugger": "error" } } },
+                "overrides": [
+                    {
+                        "includes": ["tests/**"],
+                        "linter": { "rules": { "suspicious": { "noDebugger": "off" } } }
+                    }
+                ]
+            }"#,
+        );
+        let options = EjectOptions {
+            split_overrides: true,
+            ..options()
+        };
+
+        let eslint_config = build_eslint_config(&registry, &config, &options).unwrap();
+        let overrides = eslint_config.overrides.unwrap();
+        assert!(overrides.contains("export default ["));
+        assert!(overrides.contains(r#"files: ["tests/**"]"#));
+        assert!(overrides.contains(r#""no-debugger": "off""#));
+        assert!(!overrides.contains(r#""no-debugger": "error""#));
+
+        let base = eslint_config.config;
+        assert!(base.contains(r#"import overrides from "./eslint.overrides.mjs";"#));
+        assert!(base.contains("...overrides"));
+        assert!(base.contains(r#""no-debugger": "error""#));
+        assert!(!base.contains("tests/**"));
+
+        // Inlined unless split
+        let eslint_config = build_eslint_config(&registry, &config, &options()).unwrap();
+        assert!(eslint_config.overrides.is_none());
+        assert!(eslint_config.config.contains(r#"files: ["tests/**"]"#));
+    }
+
+    #[test]
+    fn overrides_never_match_no_files() {
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let config = config(
+            r#"{
+                "linter": { "rules": { "suspicious": { "noDebugger": "error" } } },
+                "overrides": [
+                    {
+                        "includes": [],
+                        "linter": { "rules": { "suspicious": { "noDebugger": "off" } } }
+                    },
+                    {
+                        "includes": ["!vendor/**"],
+                        "linter": { "rules": { "suspicious": { "noDebugger": "warn" } } }
+                    }
+                ]
+            }"#,
+        );
+
+        // The empty includes is skipped rather than disabling the override silently
+        let overrides = collect_overrides(&config);
+        assert_eq!(overrides.len(), 1);
+        assert!(overrides[0].files.is_empty());
+        assert_eq!(overrides[0].ignores, ["vendor/**"]);
+
+        // Applied to every file but the ignored ones, without `files`
+        let eslint_config = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .config;
+        assert!(!eslint_config.contains("files: []"));
+        assert!(!eslint_config.contains(r#""no-debugger": "off""#));
+        assert!(eslint_config.contains(r#"ignores: ["vendor/**"]"#));
+        assert!(eslint_config.contains(r#""no-debugger": "warn""#));
+    }
+
+    #[test]
+    fn rules_enabled_only_in_overrides_stay_out_of_the_base() {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            ("suspicious", "noConsole", Severity::Error, &["no-console"]),
+        ]);
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": { "recommended": false, "suspicious": { "noDebugger": "error" } }
+                },
+                "overrides": [
+                    {
+                        "includes": ["scripts/**"],
+                        "linter": { "rules": { "suspicious": { "noConsole": "error" } } }
+                    }
+                ]
+            }"#,
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options()).unwrap();
+        assert_eq!(
+            eslint_config.rules.keys().collect::<Vec<_>>(),
+            ["no-debugger"]
+        );
+
+        let (base, scripts) = eslint_config
+            .config
+            .split_once(r#"files: ["scripts/**"]"#)
+            .unwrap();
+        assert!(base.contains(r#""no-debugger": "error""#));
+        assert!(!base.contains("no-console"));
+        assert!(scripts.contains(r#""no-console": "error""#));
+    }
+
+    #[test]
+    fn rules_turned_on_in_overrides_are_at_their_default_severity() {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            (
+                "suspicious",
+                "noConsole",
+                Severity::Warning,
+                &["no-console"],
+            ),
+            ("performance", "noDelete", Severity::Error, &["no-delete"]),
+        ]);
+        let config = config(
+            r#"{
+                "linter": { "rules": { "recommended": false } },
+                "overrides": [
+                    {
+                        "includes": ["scripts/**"],
+                        "linter": { "rules": { "suspicious": "on" } }
+                    },
+                    {
+                        "includes": ["tests/**"],
+                        "linter": { "rules": { "performance": { "noDelete": "on" } } }
+                    }
+                ]
+            }"#,
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .config;
+        let (_, scripts) = eslint_config
+            .split_once(r#"files: ["scripts/**"]"#)
+            .unwrap();
+        let (scripts, tests) = scripts.split_once(r#"files: ["tests/**"]"#).unwrap();
+
+        // The group turned on as a whole
+        assert!(scripts.contains(r#""no-debugger": "error""#));
+        assert!(scripts.contains(r#""no-console": "warn""#));
+        assert!(!scripts.contains("no-delete"));
+
+        // The rule turned on by itself
+        assert!(tests.contains(r#""no-delete": "error""#));
+        assert!(!tests.contains("no-debugger"));
+    }
+}
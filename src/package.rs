@@ -0,0 +1,1090 @@
+use std::collections::BTreeSet;
+use std::ffi::OsStr;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+
+use biome_configuration::Configuration;
+use serde_json::Value;
+
+use crate::cache;
+use crate::config::{self, remove_unknown_keys, strip_jsonc, unrepresented_keys};
+use crate::dependencies::{PackageManager, add_dev_dependencies};
+use crate::diagnostics::{self, Error, Level};
+use crate::eslint::{
+    BaseRules, build_eslint_config, build_eslint_config_from, build_eslint_config_types,
+    collect_base_rules,
+};
+use crate::eslintrc::build_eslintrc_config_from;
+use crate::ignores::vcs_ignore_path;
+use crate::options::{CssTarget, EjectOptions, EslintrcFormat, Target};
+use crate::output::{FileStatus, OutputFile, apply_newline, print_dry_run, resolve_newline};
+use crate::prettier::build_prettier_config;
+use crate::registry::RuleRegistry;
+use crate::report::PackageReport;
+use crate::resolve::check_installed;
+use crate::scopes::{Scope, load_scopes};
+use crate::scripts::update_scripts;
+use crate::stylelint::build_stylelint_config;
+
+/// Finds the Biome config in the directory, preferring `biome.json` over `biome.jsonc`.
+fn find_config(dir: &Path) -> Option<PathBuf> {
+    [dir.join("biome.json"), dir.join("biome.jsonc")]
+        .into_iter()
+        .find(|path| path.is_file())
+}
+
+/// Reads the raw JSON of a Biome config.
+fn read_raw(path: &Path) -> Result<Value, Error> {
+    let config = std::fs::read_to_string(path).map_err(|e| {
+        Error::new(
+            Some(path),
+            format_args!("could not read {}: {e}", path.display()),
+        )
+    })?;
+
+    parse_raw(path, &config).map_err(|e| {
+        Error::new(
+            Some(path),
+            format_args!("could not parse {}: {e}", path.display()),
+        )
+    })
+}
+
+/// Parses the raw JSON of a Biome config, reporting the line and the column of a syntax error.
+fn parse_raw(path: &Path, config: &str) -> serde_json::Result<Value> {
+    // Editors on Windows may save the file with a BOM, which serde_json rejects
+    let config = config.strip_prefix('\u{feff}').unwrap_or(config);
+
+    // Comments and trailing commas are allowed only in biome.jsonc, as in Biome
+    let config = match path.extension().and_then(OsStr::to_str) {
+        Some("jsonc") => strip_jsonc(config),
+        _ => config.to_string(),
+    };
+
+    // Leading whitespaces are left as-is, not to shift the line reported on the syntax errors
+    serde_json::from_str(&config)
+}
+
+/// Reads the raw JSON of a Biome config, with the configs in its `extends` merged in order under
+/// it, returning it along with the files read.
+///
+/// The paths are relative to the config extending them. The root config (`//`) and the packages
+/// are not resolved, warning instead.
+fn read_extended(path: &Path, visited: &mut Vec<PathBuf>) -> Result<(Value, Vec<PathBuf>), Error> {
+    let mut raw = read_raw(path)?;
+    let mut files = vec![path.to_path_buf()];
+
+    let extends = match raw.as_object_mut().and_then(|raw| raw.remove("extends")) {
+        Some(Value::Array(extends)) => extends,
+        Some(Value::String(extends)) => vec![Value::String(extends)],
+        _ => return Ok((raw, files)),
+    };
+
+    // Canonicalized not to miss the cycles through the paths spelled differently
+    visited.push(path.canonicalize().unwrap_or_else(|_| path.to_path_buf()));
+
+    let dir = path.parent().unwrap_or(Path::new("."));
+    let mut merged = Value::Object(Default::default());
+    for extend in extends {
+        let Some(extend) = extend.as_str() else {
+            continue;
+        };
+
+        let extended = dir.join(extend);
+        if extend == "//" || !extended.is_file() {
+            diagnostics::emit(
+                Level::Warning,
+                Some(path),
+                format_args!("could not resolve `{extend}` in extends, ignoring"),
+            );
+            continue;
+        }
+
+        if visited.contains(&extended.canonicalize().unwrap_or_else(|_| extended.clone())) {
+            return Err(Error::new(
+                Some(path),
+                format_args!("{} extends itself through {extend}", path.display()),
+            ));
+        }
+
+        let (extended, extended_files) = read_extended(&extended, visited)?;
+        config::merge(&mut merged, extended);
+        files.extend(extended_files);
+    }
+
+    visited.pop();
+
+    config::merge(&mut merged, raw);
+    Ok((merged, files))
+}
+
+/// Biome config loaded from a package.
+pub struct LoadedConfig {
+    /// Path to the config, e.g. `biome.json` in the package.
+    pub path: PathBuf,
+
+    /// Raw JSON with the configs in `extends` merged, without the keys unknown to Biome.
+    pub raw: Value,
+
+    pub config: Configuration,
+
+    /// Files read, i.e. the config along with the ones it extends transitively.
+    pub files: Vec<PathBuf>,
+}
+
+/// Loads the Biome config of the package, returning its path and the raw JSON along with the
+/// parsed one.
+///
+/// The config passed by `--config` takes precedence over the one found in the directory. The
+/// configs in `extends` are merged into both of them, so nothing else need resolve them.
+pub fn load_config(dir: &Path, options: &EjectOptions) -> Result<LoadedConfig, Error> {
+    let Some(path) = options.config_file.clone().or_else(|| find_config(dir)) else {
+        return Err(Error::new(
+            None,
+            format_args!(
+                "could not find biome.json or biome.jsonc in {}",
+                dir.display()
+            ),
+        ));
+    };
+
+    let (mut raw, files) = read_extended(&path, &mut Vec::new())?;
+
+    // Removed before parsing, as Biome rejects the whole config for any of them
+    for key in remove_unknown_keys(&mut raw) {
+        diagnostics::emit(
+            Level::Warning,
+            Some(&path),
+            format_args!("`{key}` in the Biome config is not supported by this version, ignoring"),
+        );
+    }
+
+    let config: Configuration = serde_json::from_value(raw.clone()).map_err(|e| {
+        Error::new(
+            Some(&path),
+            format_args!("{} is not a valid Biome config: {e}", path.display()),
+        )
+    })?;
+
+    Ok(LoadedConfig {
+        path,
+        raw,
+        config,
+        files,
+    })
+}
+
+/// Files ejected from a package, along with the coverage of the rules if the linter is enabled.
+pub struct Ejected {
+    pub outputs: Vec<OutputFile>,
+    pub report: Option<PackageReport>,
+
+    /// Files the outputs are ejected from besides the options, i.e. the Biome configs and the
+    /// `.gitignore` respected by Biome.
+    pub inputs: Vec<PathBuf>,
+}
+
+/// Ejects the Biome config in the directory into the files to write.
+pub fn eject_package(
+    registry: &RuleRegistry,
+    dir: &Path,
+    options: &EjectOptions,
+) -> Result<Ejected, Error> {
+    let mut options = EjectOptions {
+        scopes: load_scopes(dir),
+        root: dir.to_path_buf(),
+        ..options.clone()
+    };
+
+    // The scope configured explicitly takes precedence, e.g. to limit the files
+    if options.isolate_nursery {
+        options
+            .scopes
+            .entry("nursery".to_string())
+            .or_insert_with(Scope::nursery);
+    }
+
+    let LoadedConfig {
+        path: config_file,
+        raw,
+        config,
+        files: mut inputs,
+    } = load_config(dir, &options)?;
+
+    // Whether it exists or not, as creating it changes the ignores as well
+    inputs.extend(vcs_ignore_path(&config, dir));
+
+    for key in unrepresented_keys(&raw, &config) {
+        diagnostics::emit(
+            Level::Warning,
+            Some(&config_file),
+            format_args!("`{key}` in the Biome config is not supported by this version, ignoring"),
+        );
+    }
+
+    let mut outputs = Vec::<OutputFile>::new();
+    let mut report = None;
+    let mut dev_dependencies = BTreeSet::new();
+
+    // Each tool is ejected only if enabled in Biome, even if requested by --only
+    let eject_linter = options.only.includes_linter() && config.is_linter_enabled();
+    let eject_formatter = options.only.includes_formatter()
+        && options.targets.contains(&Target::Prettier)
+        && config.is_formatter_enabled();
+
+    // Only the mapping is run for the summary, printed after all the packages are ejected, whatever
+    // the targets are
+    if options.summary_only {
+        let report = eject_linter
+            .then(|| build_eslint_config(registry, &config, &options))
+            .transpose()?
+            .map(|eslint_config| PackageReport::new(dir, &eslint_config));
+
+        return Ok(Ejected {
+            outputs: Vec::new(),
+            report,
+            inputs,
+        });
+    }
+
+    if eject_linter {
+        // Resolved once for all the targets, which are checked the same whichever is requested
+        let base_rules = collect_base_rules(registry, &config, &options);
+        check_coverage(&config_file, &base_rules, &options)?;
+
+        if options.targets.contains(&Target::Eslintrc) {
+            outputs.push(OutputFile::new(
+                dir.join(match options.format {
+                    EslintrcFormat::Json => ".eslintrc.json",
+                    EslintrcFormat::Json5 => ".eslintrc.json5",
+                }),
+                build_eslintrc_config_from(&config, &options, &base_rules),
+            ));
+        }
+
+        if options.targets.contains(&Target::Eslint) {
+            let eslint_config = build_eslint_config_from(registry, &config, &options, base_rules)?;
+            report = Some(PackageReport::new(dir, &eslint_config));
+
+            // The modules accompanying the config are written next to it
+            let output = dir.join(&options.output);
+            outputs.push(OutputFile::new(output.clone(), eslint_config.config));
+
+            if options.emit_types {
+                outputs.push(OutputFile::new(
+                    output.with_extension("d.ts"),
+                    build_eslint_config_types(&config, &options),
+                ));
+            }
+
+            if let Some(overrides) = eslint_config.overrides {
+                outputs.push(OutputFile::new(
+                    output.with_file_name("eslint.overrides.mjs"),
+                    overrides,
+                ));
+            }
+
+            if options.install_plugins {
+                dev_dependencies.extend(eslint_config.packages.iter().copied());
+            }
+
+            if options.check_resolve {
+                check_installed(dir, eslint_config.packages);
+            }
+        }
+
+        // Stylelint is a target of its own, written along with whichever ESLint config
+        if options.css_target == Some(CssTarget::Stylelint) {
+            outputs.push(OutputFile::new(
+                dir.join(".stylelintrc.json"),
+                build_stylelint_config(registry, &config, &options),
+            ));
+        }
+    }
+
+    // Both of the scripts and the dependencies are updated in the same file
+    let package_json = dir.join("package.json");
+    if (options.update_scripts || !dev_dependencies.is_empty())
+        && let Ok(mut source) = std::fs::read_to_string(&package_json)
+    {
+        if options.update_scripts {
+            source = update_scripts(&source);
+        }
+
+        if !dev_dependencies.is_empty() {
+            let (updated, added) = add_dev_dependencies(&source, &dev_dependencies);
+            if !added.is_empty() {
+                eprintln!(
+                    "{}: run {}",
+                    dir.display(),
+                    PackageManager::detect(dir).install_command(&added)
+                );
+            }
+
+            source = updated;
+        }
+
+        outputs.push(OutputFile::new(package_json, source));
+    }
+
+    if eject_formatter {
+        outputs.push(OutputFile::new(
+            dir.join(".prettierrc.json"),
+            build_prettier_config(&config, &options),
+        ));
+    }
+
+    if options.archive_source {
+        // The parsed config rather than the raw file, recording what the rules were ejected from
+        // with the extended configs merged
+        let resolved = serde_json::to_string_pretty(&config).unwrap();
+        outputs.push(OutputFile::new(
+            dir.join(".biome-eject").join("biome.json"),
+            apply_newline(&resolved, resolve_newline(options.line_ending, &config)),
+        ));
+    }
+
+    // TODO: Uninstall Biome?
+
+    Ok(Ejected {
+        outputs,
+        report,
+        inputs,
+    })
+}
+
+/// Returns why the coverage of the rules fails the options, e.g. an enabled rule without any ESLint
+/// equivalent under `--fail-on-unmapped`.
+///
+/// The rules are resolved the same for every target, so the coverage is checked only once.
+fn coverage_error(base_rules: &BaseRules, options: &EjectOptions) -> Option<String> {
+    let list = |rules: &BTreeSet<String>| -> String {
+        let rules: Vec<_> = rules.iter().map(|rule| format!("  {rule}")).collect();
+        rules.join("\n")
+    };
+
+    if options.fail_on_unmapped && !base_rules.unmapped.is_empty() {
+        return Some(format!(
+            "the rules below have no ESLint equivalent:\n{}",
+            list(&base_rules.unmapped)
+        ));
+    }
+
+    if options.strict_severity_parity && !base_rules.lossy.is_empty() {
+        return Some(format!(
+            "the rules below are at a severity ESLint cannot represent:\n{}",
+            list(&base_rules.lossy)
+        ));
+    }
+
+    None
+}
+
+/// Returns the warning listing the enabled rules omitted as they have no ESLint equivalent, if any.
+fn unmapped_warning(unmapped: &BTreeSet<String>) -> Option<String> {
+    if unmapped.is_empty() {
+        return None;
+    }
+
+    let count = unmapped.len();
+    let rules: Vec<_> = unmapped.iter().map(String::as_str).collect();
+
+    Some(format!(
+        "{count} enabled Biome {} no ESLint equivalent and {} omitted: {}",
+        if count == 1 { "rule has" } else { "rules have" },
+        if count == 1 { "was" } else { "were" },
+        rules.join(", ")
+    ))
+}
+
+/// Warns about the enabled rules omitted from the configs, or fails if requested to.
+fn check_coverage(
+    config_file: &Path,
+    base_rules: &BaseRules,
+    options: &EjectOptions,
+) -> Result<(), Error> {
+    if let Some(error) = coverage_error(base_rules, options) {
+        return Err(Error::new(Some(config_file), error));
+    }
+
+    // Omitted from the config, which should not be taken for a complete migration
+    if let Some(warning) = unmapped_warning(&base_rules.unmapped) {
+        diagnostics::emit(Level::Warning, Some(config_file), format_args!("{warning}"));
+    }
+
+    if !base_rules.unresolved.is_empty() {
+        let rules: Vec<_> = base_rules.unresolved.iter().map(String::as_str).collect();
+
+        diagnostics::emit(
+            Level::Warning,
+            Some(config_file),
+            format_args!(
+                "these enabled Biome rules were omitted as their ESLint plugins are not supported yet: {}",
+                rules.join(", ")
+            ),
+        );
+    }
+
+    Ok(())
+}
+
+/// Ejects the packages on the worker threads, returning the results of each package in order.
+///
+/// The files are only built here, so writing them afterwards never races between the workers. The
+/// error of the first package failing in order is returned, if any.
+pub fn eject_packages(
+    registry: &RuleRegistry,
+    packages: &[PathBuf],
+    options: &EjectOptions,
+    concurrency: NonZeroUsize,
+) -> Result<Vec<Ejected>, Error> {
+    // Each worker takes a contiguous chunk, so joining them in order keeps the packages in order
+    let chunk_size = packages.len().div_ceil(concurrency.get()).max(1);
+
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = packages
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|dir| eject_package(registry, dir, options))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().unwrap())
+            .collect()
+    })
+}
+
+/// Checks the generated files are up to date, printing the diff of the packages which are not.
+///
+/// The packages passed the last time are skipped unless any of their files or the options change,
+/// if the cache is used.
+pub fn check_packages(
+    registry: &RuleRegistry,
+    packages: &[PathBuf],
+    options: &EjectOptions,
+    concurrency: NonZeroUsize,
+    use_cache: bool,
+) -> Result<bool, Error> {
+    let (fresh, stale): (Vec<_>, Vec<_>) = packages
+        .iter()
+        .partition(|dir| use_cache && cache::is_fresh(dir, options));
+
+    for dir in &fresh {
+        eprintln!("{}: up to date (cached)", dir.display());
+    }
+
+    let dirs: Vec<_> = stale.iter().map(|dir| dir.to_path_buf()).collect();
+    let ejected = eject_packages(registry, &dirs, options, concurrency)?;
+
+    let mut up_to_date = true;
+    for (dir, ejected) in dirs.iter().zip(&ejected) {
+        if ejected
+            .outputs
+            .iter()
+            .all(|output| output.status() == FileStatus::Unchanged)
+        {
+            if use_cache {
+                // The outputs are tracked as written, e.g. next to the path passed by --output
+                let files: Vec<_> = ejected
+                    .inputs
+                    .iter()
+                    .chain(ejected.outputs.iter().map(|output| &output.path))
+                    .cloned()
+                    .collect();
+                cache::store(dir, options, &files);
+            }
+        } else {
+            print_dry_run(&ejected.outputs);
+            up_to_date = false;
+        }
+    }
+
+    Ok(up_to_date)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use biome_diagnostics::Severity;
+
+    use super::*;
+    use crate::options::Only;
+    use crate::testing::{config, css_registry, options, registry, temp_dir};
+
+    #[test]
+    fn summary_only_builds_no_files() {
+        let dir = temp_dir("summary-only");
+        fs::write(
+            dir.join("biome.json"),
+            r#"{
+                "formatter": { "enabled": true },
+                "linter": {
+                    "rules": { "suspicious": { "noDebugger": "error", "noDoubleEquals": "error" } }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            ("suspicious", "noDoubleEquals", Severity::Error, &[]),
+        ]);
+        let options = EjectOptions {
+            targets: vec![Target::Eslint, Target::Eslintrc],
+            summary_only: true,
+            archive_source: true,
+            ..options()
+        };
+
+        let ejected = eject_package(&registry, &dir, &options).unwrap();
+        assert!(ejected.outputs.is_empty());
+
+        let report = ejected.report.unwrap();
+        assert!(report.summary().starts_with("mapped: 1\nunmapped: 1\n"));
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn coverage_is_checked_for_every_target() {
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Information,
+            &["no-debugger"],
+        )]);
+        let config =
+            config(r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "info" } } } }"#);
+        let options = EjectOptions {
+            targets: vec![Target::Eslintrc],
+            ..options()
+        };
+
+        let base_rules = collect_base_rules(&registry, &config, &options);
+        assert_eq!(coverage_error(&base_rules, &options), None);
+
+        let options = EjectOptions {
+            strict_severity_parity: true,
+            ..options
+        };
+        let error = coverage_error(&base_rules, &options).unwrap();
+        assert!(error.contains("  suspicious/noDebugger"));
+    }
+
+    #[test]
+    fn rules_without_eslint_equivalent_are_warned_about() {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            ("suspicious", "noDoubleEquals", Severity::Error, &[]),
+            ("suspicious", "noConsole", Severity::Error, &[]),
+        ]);
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": {
+                        "suspicious": {
+                            "noDebugger": "error",
+                            "noDoubleEquals": "error",
+                            "noConsole": "error"
+                        }
+                    }
+                }
+            }"#,
+        );
+
+        let base_rules = collect_base_rules(&registry, &config, &options());
+        assert_eq!(base_rules.rules.keys().collect::<Vec<_>>(), ["no-debugger"]);
+        assert_eq!(
+            unmapped_warning(&base_rules.unmapped).as_deref(),
+            Some(
+                "2 enabled Biome rules have no ESLint equivalent and were omitted: \
+                 suspicious/noConsole, suspicious/noDoubleEquals"
+            )
+        );
+
+        let unmapped = BTreeSet::from(["suspicious/noConsole".to_string()]);
+        assert_eq!(
+            unmapped_warning(&unmapped).as_deref(),
+            Some(
+                "1 enabled Biome rule has no ESLint equivalent and was omitted: suspicious/noConsole"
+            )
+        );
+        assert_eq!(unmapped_warning(&BTreeSet::new()), None);
+    }
+
+    #[test]
+    fn stylelint_config_is_written_along_with_eslintrc() {
+        let dir = temp_dir("stylelint-eslintrc");
+        fs::write(
+            dir.join("biome.json"),
+            r#"{ "linter": { "rules": { "suspicious": { "noEmptyBlock": "error" } } } }"#,
+        )
+        .unwrap();
+
+        let registry = css_registry(&[(
+            "suspicious",
+            "noEmptyBlock",
+            Severity::Warning,
+            &["block-no-empty"],
+        )]);
+        let options = EjectOptions {
+            targets: vec![Target::Eslintrc],
+            css_target: Some(CssTarget::Stylelint),
+            ..options()
+        };
+
+        let ejected = eject_package(&registry, &dir, &options).unwrap();
+        let paths: Vec<_> = ejected.outputs.iter().map(|output| &output.path).collect();
+        assert_eq!(
+            paths,
+            [&dir.join(".eslintrc.json"), &dir.join(".stylelintrc.json")]
+        );
+        assert!(ejected.outputs[1].contents.contains("block-no-empty"));
+    }
+
+    #[test]
+    fn every_target_is_ejected_in_one_run() {
+        let dir = temp_dir("targets");
+        fs::write(
+            dir.join("biome.json"),
+            r#"{
+                "formatter": { "enabled": true, "indentStyle": "space" },
+                "linter": { "rules": { "suspicious": { "noDebugger": "error" } } }
+            }"#,
+        )
+        .unwrap();
+
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let options = EjectOptions {
+            targets: vec![Target::Eslint, Target::Prettier],
+            ..options()
+        };
+
+        let ejected = eject_package(&registry, &dir, &options).unwrap();
+        let paths: Vec<_> = ejected.outputs.iter().map(|output| &output.path).collect();
+        assert_eq!(
+            paths,
+            [
+                &dir.join("eslint.config.mjs"),
+                &dir.join(".prettierrc.json")
+            ]
+        );
+
+        let options = EjectOptions {
+            targets: vec![Target::Eslint],
+            ..options
+        };
+        let ejected = eject_package(&registry, &dir, &options).unwrap();
+        assert_eq!(ejected.outputs.len(), 1);
+    }
+
+    #[test]
+    fn archived_config_is_merged_with_extends() {
+        let dir = temp_dir("archive-extends");
+        fs::write(
+            dir.join("base.json"),
+            r#"{
+                "formatter": { "indentWidth": 4 },
+                "linter": { "rules": { "suspicious": { "noDebugger": "error", "noDoubleEquals": "error" } } }
+            }"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("biome.json"),
+            r#"{
+                "extends": ["./base.json"],
+                "linter": { "rules": { "suspicious": { "noDebugger": "warn" } } }
+            }"#,
+        )
+        .unwrap();
+
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            ("suspicious", "noDoubleEquals", Severity::Error, &["eqeqeq"]),
+        ]);
+        let options = EjectOptions {
+            archive_source: true,
+            ..options()
+        };
+
+        let ejected = eject_package(&registry, &dir, &options).unwrap();
+        let archived = ejected
+            .outputs
+            .iter()
+            .find(|output| output.path == dir.join(".biome-eject").join("biome.json"))
+            .unwrap();
+        let archived: Value = serde_json::from_str(&archived.contents).unwrap();
+
+        assert!(archived["extends"].is_null());
+        assert_eq!(archived["formatter"]["indentWidth"], 4);
+
+        let rules = &archived["linter"]["rules"]["suspicious"];
+        assert_eq!(rules["noDebugger"], "warn");
+        assert_eq!(rules["noDoubleEquals"], "error");
+    }
+
+    #[test]
+    fn unknown_keys_do_not_fail_the_config() {
+        let dir = temp_dir("unknown-keys");
+        fs::write(
+            dir.join("biome.json"),
+            r#"{ "futureFeature": {}, "formatter": { "indentWidth": 4 } }"#,
+        )
+        .unwrap();
+
+        let LoadedConfig { raw, config, .. } = load_config(&dir, &options()).unwrap();
+        assert!(raw.get("futureFeature").is_none());
+        assert!(config.is_formatter_enabled());
+    }
+
+    #[test]
+    fn concurrent_ejection_matches_the_sequential_path() {
+        let root = temp_dir("concurrency");
+        let packages: Vec<_> = ["a", "b", "c", "d", "e"]
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                let dir = root.join(name);
+                fs::create_dir(&dir).unwrap();
+
+                let level = if index % 2 == 0 { "error" } else { "warn" };
+                fs::write(
+                    dir.join("biome.json"),
+                    format!(
+                        r#"{{ "linter": {{ "rules": {{ "suspicious": {{ "noDebugger": "{level}" }} }} }} }}"#
+                    ),
+                )
+                .unwrap();
+
+                dir
+            })
+            .collect();
+
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let options = options();
+
+        let contents = |concurrency: usize| -> Vec<_> {
+            eject_packages(
+                &registry,
+                &packages,
+                &options,
+                NonZeroUsize::new(concurrency).unwrap(),
+            )
+            .unwrap()
+            .into_iter()
+            .flat_map(|ejected| ejected.outputs)
+            .map(|output| (output.path, output.contents))
+            .collect()
+        };
+
+        let sequential = contents(1);
+        assert_eq!(sequential.len(), packages.len());
+        assert_eq!(sequential[0].0, packages[0].join("eslint.config.mjs"));
+
+        assert_eq!(contents(2), sequential);
+        assert_eq!(contents(8), sequential);
+    }
+
+    #[test]
+    fn config_with_bom_is_ejected() {
+        let dir = temp_dir("bom");
+        fs::write(
+            dir.join("biome.json"),
+            "\u{feff}{ \"linter\": { \"rules\": { \"suspicious\": { \"noDebugger\": \"warn\" } } } }\r\n\n  ",
+        )
+        .unwrap();
+
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+
+        let ejected = eject_package(&registry, &dir, &options()).unwrap();
+        assert!(
+            ejected.outputs[0]
+                .contents
+                .contains(r#""no-debugger": "warn""#)
+        );
+    }
+
+    #[test]
+    fn types_are_declared_next_to_the_config() {
+        let dir = temp_dir("emit-types");
+        fs::write(dir.join("biome.json"), "{}").unwrap();
+
+        let options = EjectOptions {
+            emit_types: true,
+            ..options()
+        };
+
+        let ejected = eject_package(&registry(&[]), &dir, &options).unwrap();
+        let types = ejected
+            .outputs
+            .iter()
+            .find(|output| output.path == dir.join("eslint.config.d.ts"))
+            .unwrap();
+        assert!(
+            types
+                .contents
+                .contains(r#"import type { Linter } from "eslint";"#)
+        );
+        assert!(
+            types
+                .contents
+                .contains("declare const config: Linter.Config[];")
+        );
+        assert!(types.contents.contains("export default config;"));
+
+        let ejected = eject_package(&registry(&[]), &dir, &crate::testing::options()).unwrap();
+        assert_eq!(ejected.outputs.len(), 1);
+    }
+
+    #[test]
+    fn tools_disabled_in_biome_are_not_ejected() {
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let options = EjectOptions {
+            targets: vec![Target::Eslint, Target::Prettier],
+            only: Only::All,
+            ..options()
+        };
+
+        let dir = temp_dir("formatter-disabled");
+        fs::write(
+            dir.join("biome.json"),
+            r#"{
+                "formatter": { "enabled": false },
+                "linter": { "rules": { "suspicious": { "noDebugger": "error" } } }
+            }"#,
+        )
+        .unwrap();
+
+        let ejected = eject_package(&registry, &dir, &options).unwrap();
+        let paths: Vec<_> = ejected.outputs.iter().map(|output| &output.path).collect();
+        assert_eq!(paths, [&dir.join("eslint.config.mjs")]);
+
+        let dir = temp_dir("linter-disabled");
+        fs::write(
+            dir.join("biome.json"),
+            r#"{ "formatter": { "enabled": true }, "linter": { "enabled": false } }"#,
+        )
+        .unwrap();
+
+        let ejected = eject_package(&registry, &dir, &options).unwrap();
+        let paths: Vec<_> = ejected.outputs.iter().map(|output| &output.path).collect();
+        assert_eq!(paths, [&dir.join(".prettierrc.json")]);
+    }
+
+    #[test]
+    fn nursery_rules_are_isolated_into_the_experimental_object() {
+        let dir = temp_dir("isolate-nursery");
+        fs::write(
+            dir.join("biome.json"),
+            r#"{
+                "linter": {
+                    "rules": {
+                        "nursery": "error",
+                        "suspicious": { "noDebugger": "error" }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let registry = registry(&[
+            (
+                "nursery",
+                "noExperiment",
+                Severity::Error,
+                &["no-experiment"],
+            ),
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+        ]);
+        let options = EjectOptions {
+            isolate_nursery: true,
+            ..options()
+        };
+
+        let ejected = eject_package(&registry, &dir, &options).unwrap();
+        let (base, nursery) = ejected.outputs[0]
+            .contents
+            .split_once("// Experimental: ported from the nursery rules of Biome")
+            .unwrap();
+
+        assert!(base.contains(r#""no-debugger": "error""#));
+        assert!(!base.contains("no-experiment"));
+
+        assert!(nursery.contains(r#"name: "biome-eject/nursery-experimental""#));
+        assert!(nursery.contains(r#""no-experiment": "error""#));
+        assert!(!nursery.contains("no-debugger"));
+    }
+
+    #[test]
+    fn config_passed_explicitly_is_preferred() {
+        let dir = temp_dir("config-file");
+        fs::write(
+            dir.join("biome.json"),
+            r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "off" } } } }"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.join("biome.web.jsonc"),
+            r#"{
+                // Passed by --config
+                "linter": { "rules": { "suspicious": { "noDebugger": "warn" } } }
+            }"#,
+        )
+        .unwrap();
+
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+        let options = EjectOptions {
+            config_file: Some(dir.join("biome.web.jsonc")),
+            ..options()
+        };
+
+        let ejected = eject_package(&registry, &dir, &options).unwrap();
+        assert_eq!(ejected.outputs[0].path, dir.join("eslint.config.mjs"));
+        assert!(
+            ejected.outputs[0]
+                .contents
+                .contains(r#""no-debugger": "warn""#)
+        );
+    }
+
+    #[test]
+    fn files_are_only_built_for_the_dry_run() {
+        let dir = temp_dir("dry-run-build");
+        fs::write(
+            dir.join("biome.json"),
+            r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#,
+        )
+        .unwrap();
+
+        let registry = registry(&[(
+            "suspicious",
+            "noDebugger",
+            Severity::Error,
+            &["no-debugger"],
+        )]);
+
+        let ejected = eject_package(&registry, &dir, &options()).unwrap();
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        let output = &ejected.outputs[0];
+        assert_eq!(output.status(), FileStatus::Created);
+        assert!(output.contents.contains(r#""no-debugger": "error""#));
+    }
+
+    #[test]
+    fn missing_config_is_told_from_malformed_one() {
+        let dir = temp_dir("missing-config");
+        assert_eq!(find_config(&dir), None);
+
+        fs::write(dir.join("biome.jsonc"), "{}").unwrap();
+        assert_eq!(find_config(&dir), Some(dir.join("biome.jsonc")));
+
+        fs::write(dir.join("biome.json"), "{}").unwrap();
+        assert_eq!(find_config(&dir), Some(dir.join("biome.json")));
+
+        let error = parse_raw(
+            Path::new("biome.json"),
+            "{\n  \"linter\": { \"enabled\": true },\n  \"formatter\": { \"enabled\": tru }\n}\n",
+        )
+        .unwrap_err();
+        assert!(error.is_syntax());
+        assert_eq!(error.line(), 3);
+        assert!(error.column() > 0);
+        assert!(error.to_string().contains("line 3 column"));
+    }
+
+    #[test]
+    fn errors_are_returned_rather_than_exiting() {
+        let dir = temp_dir("errors");
+
+        let error = load_config(&dir, &options()).err().unwrap();
+        assert_eq!(error.file, None);
+        assert!(error.message.starts_with("could not find biome.json"));
+
+        // Extending itself through another config
+        fs::write(dir.join("biome.json"), r#"{ "extends": ["base.json"] }"#).unwrap();
+        fs::write(dir.join("base.json"), r#"{ "extends": ["biome.json"] }"#).unwrap();
+        let error = load_config(&dir, &options()).err().unwrap();
+        assert_eq!(error.file, Some(dir.join("base.json")));
+        assert!(error.message.ends_with("extends itself through biome.json"));
+
+        fs::write(
+            dir.join("biome.json"),
+            r#"{ "linter": { "enabled": tru } }"#,
+        )
+        .unwrap();
+        let error = eject_package(&registry(&[]), &dir, &options())
+            .err()
+            .unwrap();
+        assert_eq!(error.file, Some(dir.join("biome.json")));
+        assert!(error.message.starts_with("could not parse"));
+
+        // The coverage required by the options
+        fs::write(
+            dir.join("biome.json"),
+            r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#,
+        )
+        .unwrap();
+        let registry = registry(&[("suspicious", "noDebugger", Severity::Error, &[])]);
+        let options = EjectOptions {
+            fail_on_unmapped: true,
+            ..options()
+        };
+        let error = eject_packages(&registry, &[dir.clone()], &options, NonZeroUsize::MIN)
+            .err()
+            .unwrap();
+        assert!(error.message.contains("  suspicious/noDebugger"));
+    }
+}
@@ -0,0 +1,213 @@
+use biome_configuration::Configuration;
+use serde_json::{Map, Value, json};
+
+use crate::options::EjectOptions;
+use crate::output::{apply_newline, resolve_newline};
+
+/// Formatter settings of Biome translatable into Prettier, with their defaults.
+fn biome_defaults() -> Value {
+    json!({
+        "lineWidth": 80,
+        "indentWidth": 2,
+        "indentStyle": "tab",
+        "lineEnding": "lf",
+        "quoteStyle": "double",
+        "jsxQuoteStyle": "double",
+        "semicolons": "always",
+        "trailingCommas": "all",
+        "arrowParentheses": "always",
+        "bracketSpacing": true,
+        "bracketSameLine": false,
+        "quoteProperties": "asNeeded",
+    })
+}
+
+fn prettier_defaults() -> Value {
+    json!({
+        "printWidth": 80,
+        "tabWidth": 2,
+        "useTabs": false,
+        "endOfLine": "lf",
+        "singleQuote": false,
+        "jsxSingleQuote": false,
+        "semi": true,
+        "trailingComma": "all",
+        "arrowParens": "always",
+        "bracketSpacing": true,
+        "bracketSameLine": false,
+        "quoteProps": "as-needed",
+    })
+}
+
+/// Translates the formatter setting of Biome into the option of Prettier.
+fn translate(key: &str, value: &Value) -> Option<(&'static str, Value)> {
+    let is = |expected: &str| Value::Bool(value.as_str() == Some(expected));
+
+    Some(match key {
+        "lineWidth" => ("printWidth", value.clone()),
+        "indentWidth" => ("tabWidth", value.clone()),
+        "indentStyle" => ("useTabs", is("tab")),
+        "lineEnding" => ("endOfLine", value.clone()),
+        "quoteStyle" => ("singleQuote", is("single")),
+        "jsxQuoteStyle" => ("jsxSingleQuote", is("single")),
+        "semicolons" => ("semi", is("always")),
+        "trailingCommas" => ("trailingComma", value.clone()),
+        "arrowParentheses" => match value.as_str() {
+            Some("asNeeded") => ("arrowParens", json!("avoid")),
+            _ => ("arrowParens", json!("always")),
+        },
+        "bracketSpacing" => ("bracketSpacing", value.clone()),
+        "bracketSameLine" => ("bracketSameLine", value.clone()),
+        "quoteProperties" => match value.as_str() {
+            Some("preserve") => ("quoteProps", json!("preserve")),
+            _ => ("quoteProps", json!("as-needed")),
+        },
+        _ => return None,
+    })
+}
+
+/// Looks up the formatter setting, preferring the JavaScript-specific one over the global one.
+fn lookup(settings: &Value, key: &str) -> Option<Value> {
+    ["/javascript/formatter", "/formatter"]
+        .iter()
+        .filter_map(|pointer| settings.pointer(&format!("{pointer}/{key}")))
+        .find(|value| !value.is_null())
+        .cloned()
+}
+
+/// Translates the formatter settings configured in the section, without any defaults.
+fn translate_configured(settings: &Value) -> Map<String, Value> {
+    let biome_defaults = biome_defaults();
+
+    biome_defaults
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter_map(|(key, _)| translate(key, &lookup(settings, key)?))
+        .map(|(key, value)| (key.to_string(), value))
+        .collect()
+}
+
+// { files: ["scripts/**"], excludeFiles: [...], options: { ... } }
+fn translate_override(pattern: &Value) -> Option<Value> {
+    let options = translate_configured(pattern);
+    if options.is_empty() {
+        return None;
+    }
+
+    let (excludes, includes): (Vec<_>, Vec<_>) = pattern
+        .get("includes")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .partition(|glob| glob.starts_with('!'));
+
+    // An override without `includes` applies to every file
+    let files = if includes.is_empty() {
+        json!(["**/*"])
+    } else {
+        json!(includes)
+    };
+
+    let mut prettier_override = Map::new();
+    prettier_override.insert("files".to_string(), files);
+
+    if !excludes.is_empty() {
+        let excludes: Vec<_> = excludes.iter().map(|glob| &glob[1..]).collect();
+        prettier_override.insert("excludeFiles".to_string(), json!(excludes));
+    }
+
+    prettier_override.insert("options".to_string(), Value::Object(options));
+
+    Some(Value::Object(prettier_override))
+}
+
+/// Builds `.prettierrc.json` from the formatter settings and the overrides changing them.
+///
+/// Only the options differing from the defaults of Prettier are emitted, so the settings left as
+/// default in Biome but differing in Prettier (e.g. indenting with tabs) are still carried over.
+pub fn build_prettier_config(config: &Configuration, options: &EjectOptions) -> String {
+    let settings = json!({
+        "formatter": config.formatter,
+        "javascript": config.javascript,
+    });
+
+    let defaults = prettier_defaults();
+
+    let mut prettier = Map::new();
+    for (key, default) in biome_defaults().as_object().into_iter().flatten() {
+        let value = lookup(&settings, key).unwrap_or_else(|| default.clone());
+
+        if let Some((key, value)) = translate(key, &value)
+            && defaults.get(key) != Some(&value)
+        {
+            prettier.insert(key.to_string(), value);
+        }
+    }
+
+    let overrides: Vec<_> = serde_json::to_value(&config.overrides)
+        .ok()
+        .and_then(|overrides| overrides.as_array().cloned())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(translate_override)
+        .collect();
+
+    if !overrides.is_empty() {
+        prettier.insert("overrides".to_string(), Value::Array(overrides));
+    }
+
+    let printed = serde_json::to_string_pretty(&prettier).unwrap();
+
+    apply_newline(&printed, resolve_newline(options.line_ending, config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{config, options};
+
+    #[test]
+    fn formatter_overrides_become_prettier_overrides() {
+        let config = config(
+            r#"{
+                "formatter": { "indentStyle": "space" },
+                "overrides": [
+                    {
+                        "includes": ["scripts/**", "!scripts/vendor/**"],
+                        "javascript": { "formatter": { "quoteStyle": "single" } }
+                    },
+                    {
+                        "includes": ["tests/**"],
+                        "linter": { "rules": { "suspicious": { "noDebugger": "off" } } }
+                    }
+                ]
+            }"#,
+        );
+
+        let prettier: Value =
+            serde_json::from_str(&build_prettier_config(&config, &options())).unwrap();
+
+        // Only the override changing the formatter settings is carried over
+        assert_eq!(
+            prettier["overrides"],
+            json!([
+                {
+                    "files": ["scripts/**"],
+                    "excludeFiles": ["scripts/vendor/**"],
+                    "options": { "singleQuote": true }
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn config_without_formatter_overrides_has_no_overrides() {
+        let config = config(r#"{ "formatter": { "indentStyle": "space" } }"#);
+
+        let prettier: Value =
+            serde_json::from_str(&build_prettier_config(&config, &options())).unwrap();
+        assert!(prettier.get("overrides").is_none());
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::BTreeMap;
+#[cfg(feature = "registry")]
+use std::sync::OnceLock;
+
+use biome_analyze::{RuleMetadata, RuleSource};
+use biome_diagnostics::Severity;
+
+use crate::eslint::RuleSourceKind;
+use crate::mapping::Mapping;
+
+/// A rule of ESLint or its ecosystem which a Biome rule is derived from.
+#[derive(Clone, Debug)]
+pub struct RuleOrigin {
+    pub kind: RuleSourceKind,
+
+    /// Name of the rule to configure, prefixed with the namespace of the plugin if any.
+    pub name: String,
+}
+
+impl From<&RuleSource> for RuleOrigin {
+    fn from(value: &RuleSource) -> Self {
+        let kind = RuleSourceKind::from(value);
+
+        Self {
+            kind,
+            name: kind.to_namespaced_rule_name(value.as_rule_name()),
+        }
+    }
+}
+
+impl RuleOrigin {
+    pub fn from_namespaced_rule_name(name: &str) -> Self {
+        Self {
+            kind: RuleSourceKind::from_namespaced_rule_name(name),
+            name: name.to_string(),
+        }
+    }
+}
+
+/// The subset of the rule metadata required to eject a rule.
+#[derive(Clone, Debug)]
+pub struct RuleInfo {
+    pub severity: Severity,
+    pub recommended: bool,
+    pub sources: Vec<RuleOrigin>,
+}
+
+impl From<&RuleMetadata> for RuleInfo {
+    fn from(value: &RuleMetadata) -> Self {
+        Self {
+            severity: value.severity,
+            recommended: value.recommended,
+            sources: value
+                .sources
+                .iter()
+                .map(|rule_source| RuleOrigin::from(&rule_source.source))
+                .collect(),
+        }
+    }
+}
+
+pub type Rules = BTreeMap<&'static str, RuleInfo>;
+pub type Groups = BTreeMap<&'static str, Rules>;
+
+#[derive(Default)]
+pub struct RuleRegistry {
+    pub groups: Groups,
+    pub css_groups: Groups,
+}
+
+impl RuleRegistry {
+    /// Collects the rules of the Biome analyzers.
+    #[cfg(feature = "registry")]
+    pub fn visit() -> Self {
+        let mut registry = Self::default();
+
+        biome_js_analyze::visit_registry(&mut registry);
+        biome_css_analyze::visit_registry(&mut registry);
+
+        registry
+    }
+
+    /// Collects the rules of the Biome analyzers on the first call, returning the same ones later.
+    #[cfg(feature = "registry")]
+    pub fn shared() -> &'static Self {
+        static REGISTRY: OnceLock<RuleRegistry> = OnceLock::new();
+
+        REGISTRY.get_or_init(Self::visit)
+    }
+
+    /// Whether the rule is known to the analyzers of any language.
+    pub fn contains(&self, group: &str, rule: &str) -> bool {
+        [&self.groups, &self.css_groups].iter().any(|groups| {
+            groups
+                .get(group)
+                .is_some_and(|rules| rules.contains_key(rule))
+        })
+    }
+
+    /// Replaces the sources of the rules with the mapping, adding the rules not in the registry.
+    ///
+    /// The rules added from the mapping default to the error severity, as their metadata is unknown.
+    pub fn apply_mapping(&mut self, mapping: &Mapping) {
+        for (&(group, rule), origin) in mapping.iter() {
+            self.groups
+                .entry(group)
+                .or_default()
+                .entry(rule)
+                .and_modify(|info| info.sources = vec![origin.clone()])
+                .or_insert_with(|| RuleInfo {
+                    severity: Severity::Error,
+                    recommended: false,
+                    sources: vec![origin.clone()],
+                });
+        }
+    }
+}
+
+#[cfg(feature = "registry")]
+mod visitor {
+    use biome_analyze::{Queryable, RegistryVisitor, Rule, RuleGroup};
+    use biome_css_syntax::CssLanguage;
+    use biome_js_syntax::JsLanguage;
+
+    use super::{RuleInfo, RuleRegistry};
+
+    impl RegistryVisitor<JsLanguage> for RuleRegistry {
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule<Query: Queryable<Language = JsLanguage, Output: Clone>> + 'static,
+        {
+            let group = R::Group::NAME;
+            let metadata = R::METADATA;
+
+            self.groups
+                .entry(group)
+                .or_insert_with(Default::default)
+                .insert(metadata.name, RuleInfo::from(&metadata));
+        }
+    }
+
+    impl RegistryVisitor<CssLanguage> for RuleRegistry {
+        fn record_rule<R>(&mut self)
+        where
+            R: Rule<Query: Queryable<Language = CssLanguage, Output: Clone>> + 'static,
+        {
+            let group = R::Group::NAME;
+            let metadata = R::METADATA;
+
+            self.css_groups
+                .entry(group)
+                .or_insert_with(Default::default)
+                .insert(metadata.name, RuleInfo::from(&metadata));
+        }
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value, json};
+
+use crate::eslint::{EslintConfig, EslintLevel, RuleSourceKind};
+
+/// Coverage of the rules ejected from a package, for the machine-readable report.
+pub struct PackageReport {
+    pub dir: PathBuf,
+
+    /// ESLint rules configured for all the files, along with their levels.
+    pub rules: BTreeMap<String, EslintLevel>,
+
+    /// Enabled rules ejected into ESLint rules, as `group/rule`.
+    pub mapped: BTreeSet<String>,
+
+    /// Enabled rules without any ESLint equivalent, as `group/rule`.
+    pub unmapped: BTreeSet<String>,
+
+    /// Enabled rules omitted as their plugins are not supported yet, as `group/rule`.
+    pub unresolved: BTreeSet<String>,
+
+    /// Packages imported from the modules.
+    pub packages: BTreeSet<&'static str>,
+}
+
+impl PackageReport {
+    pub fn new(dir: &Path, config: &EslintConfig) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+            rules: config.rules.clone(),
+            mapped: config.mapped.clone(),
+            unmapped: config.unmapped.clone(),
+            unresolved: config.unresolved.clone(),
+            packages: config.packages.clone(),
+        }
+    }
+
+    /// Summarizes how much of the enabled rules are covered by the config.
+    pub fn summary(&self) -> String {
+        let mut summary = format!(
+            "mapped: {}\nunmapped: {}\n",
+            self.mapped.len(),
+            self.unmapped.len()
+        );
+
+        for rule in &self.unmapped {
+            summary.push_str(&format!("  {rule}\n"));
+        }
+
+        // The plugins of these are not supported yet, so the config may be incomplete
+        if !self.unresolved.is_empty() {
+            summary.push_str(&format!(
+                "unresolved: {} (the config may be incomplete)\n",
+                self.unresolved.len()
+            ));
+        }
+
+        let packages: Vec<_> = self.packages.iter().copied().collect();
+        summary.push_str(&format!("packages: {}\n", packages.join(", ")));
+
+        summary
+    }
+}
+
+/// Report of the packages ejected in a run, for CI to gate on, e.g. no unmapped rules.
+pub struct ConversionReport<'a> {
+    pub packages: &'a [PackageReport],
+}
+
+impl ConversionReport<'_> {
+    /// Serializes the report, keyed by the directories of the packages.
+    ///
+    /// The rules are listed by their ESLint names, along with the namespaces of their plugins
+    /// (`eslint` for the core rules) and their levels.
+    pub fn to_json(&self) -> Value {
+        let packages: Map<String, Value> = self
+            .packages
+            .iter()
+            .map(|report| {
+                let rules: Map<String, Value> = report
+                    .rules
+                    .iter()
+                    .map(|(name, level)| {
+                        let source = RuleSourceKind::from_namespaced_rule_name(name)
+                            .as_namespace()
+                            .unwrap_or("eslint");
+
+                        (
+                            name.clone(),
+                            json!({ "source": source, "level": level.as_str() }),
+                        )
+                    })
+                    .collect();
+
+                let entry = json!({
+                    "rules": rules,
+                    "mapped": report.mapped,
+                    "unmapped": report.unmapped,
+                    "unresolved": report.unresolved,
+                    "packages": report.packages,
+                });
+
+                (report.dir.display().to_string(), entry)
+            })
+            .collect();
+
+        json!({ "packages": packages })
+    }
+}
+
+/// Writes the report of the packages in JSON to the file, or to stdout if the path is `-`.
+///
+/// Nothing else is written to stdout along with the report, as the progress and the summary are
+/// always written to stderr, and `--dry-run` is rejected when the report is printed to stdout.
+pub fn write_report(reports: &[PackageReport], path: &Path) {
+    let report = ConversionReport { packages: reports };
+    let printed = serde_json::to_string_pretty(&report.to_json()).unwrap() + "\n";
+
+    if path == Path::new("-") {
+        std::io::stdout().write_all(printed.as_bytes()).unwrap();
+    } else {
+        std::fs::write(path, printed).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use biome_diagnostics::Severity;
+
+    use super::*;
+    use crate::eslint::build_eslint_config;
+    use crate::testing::{config, options, registry};
+
+    /// Report of a package enabling a rule of each kind: mapped to a core rule or a plugin rule,
+    /// without any equivalent, and of a plugin not supported yet.
+    fn report() -> PackageReport {
+        let registry = registry(&[
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+            (
+                "suspicious",
+                "noExplicitAny",
+                Severity::Error,
+                &["@typescript-eslint/no-explicit-any"],
+            ),
+            ("suspicious", "noDoubleEquals", Severity::Error, &[]),
+            (
+                "suspicious",
+                "noConsole",
+                Severity::Error,
+                &["solid/no-console"],
+            ),
+        ]);
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": {
+                        "suspicious": {
+                            "noDebugger": "error",
+                            "noExplicitAny": "warn",
+                            "noDoubleEquals": "error",
+                            "noConsole": "error"
+                        }
+                    }
+                }
+            }"#,
+        );
+
+        PackageReport::new(
+            Path::new("packages/web"),
+            &build_eslint_config(&registry, &config, &options()).unwrap(),
+        )
+    }
+
+    #[test]
+    fn summary_counts_the_rules_and_lists_the_packages() {
+        assert_eq!(
+            report().summary(),
+            concat!(
+                "mapped: 2\n",
+                "unmapped: 1\n",
+                "  suspicious/noDoubleEquals\n",
+                "unresolved: 1 (the config may be incomplete)\n",
+                "packages: eslint, typescript-eslint\n",
+            )
+        );
+    }
+
+    #[test]
+    fn report_is_serialized_by_the_packages() {
+        let reports = [report()];
+        let report = ConversionReport { packages: &reports };
+
+        assert_eq!(
+            report.to_json(),
+            json!({
+                "packages": {
+                    "packages/web": {
+                        "rules": {
+                            "@typescript-eslint/no-explicit-any": {
+                                "source": "@typescript-eslint",
+                                "level": "warn"
+                            },
+                            "no-debugger": { "source": "eslint", "level": "error" }
+                        },
+                        "mapped": ["suspicious/noDebugger", "suspicious/noExplicitAny"],
+                        "unmapped": ["suspicious/noDoubleEquals"],
+                        "unresolved": ["suspicious/noConsole"],
+                        "packages": ["eslint", "typescript-eslint"]
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn report_is_written_to_the_file() {
+        let path = crate::testing::temp_dir("report").join("report.json");
+        write_report(&[report()], &path);
+
+        let written: Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(
+            written["packages"]["packages/web"]["unmapped"],
+            json!(["suspicious/noDoubleEquals"])
+        );
+    }
+}
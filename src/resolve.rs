@@ -0,0 +1,65 @@
+use std::path::Path;
+
+use crate::diagnostics::warning;
+
+/// Whether the package is installed in `node_modules` of the directory or any of its ancestors,
+/// the same as Node.js searches the packages.
+fn is_installed(dir: &Path, package: &str) -> bool {
+    let dir = dir.canonicalize().unwrap_or_else(|_| dir.to_path_buf());
+
+    dir.ancestors()
+        .any(|ancestor| ancestor.join("node_modules").join(package).is_dir())
+}
+
+/// Warns about the packages not installed for the directory.
+///
+/// This only looks up the directories of the packages without running anything, so it cannot tell
+/// whether they are actually loadable.
+pub fn check_installed<'a>(dir: &Path, packages: impl IntoIterator<Item = &'a str>) {
+    for package in packages {
+        if !is_installed(dir, package) {
+            warning!(
+                "{package} is not found in node_modules of {}",
+                dir.display()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::testing::temp_dir;
+
+    #[test]
+    fn packages_are_searched_up_the_ancestors() {
+        let root = temp_dir("resolve");
+        let package = root.join("packages").join("app");
+        fs::create_dir_all(&package).unwrap();
+        fs::create_dir_all(
+            root.join("node_modules")
+                .join("@stylistic")
+                .join("eslint-plugin"),
+        )
+        .unwrap();
+        fs::create_dir_all(package.join("node_modules").join("eslint-plugin-jest")).unwrap();
+
+        assert!(is_installed(&package, "eslint-plugin-jest"));
+        assert!(is_installed(&package, "@stylistic/eslint-plugin"));
+        assert!(!is_installed(&root, "eslint-plugin-jest"));
+    }
+
+    #[test]
+    fn packages_absent_from_node_modules_are_not_installed() {
+        let root = temp_dir("resolve-absent");
+        fs::create_dir_all(root.join("node_modules").join("eslint-plugin-jest")).unwrap();
+
+        // Only the directory of the package counts, not a file of the same name
+        fs::write(root.join("node_modules").join("eslint-plugin-n"), "").unwrap();
+
+        assert!(!is_installed(&root, "eslint-plugin-n"));
+        assert!(!is_installed(&root, "eslint-plugin-unicorn"));
+    }
+}
@@ -0,0 +1,225 @@
+use biome_configuration::Rules as RulesConfiguration;
+use serde_json::{Value, json};
+
+/// Translators of the options, keyed by the ESLint rule each is for.
+///
+/// A rule missing in the table is ejected with the level only.
+const TRANSLATORS: &[(&str, fn(&Value) -> Option<Value>)] = &[
+    ("@typescript-eslint/array-type", array_type),
+    (
+        "@typescript-eslint/consistent-type-imports",
+        consistent_type_imports,
+    ),
+    ("no-console", no_console),
+    ("sonarjs/cognitive-complexity", cognitive_complexity),
+    ("unicorn/filename-case", filename_case),
+];
+
+/// Translates the options of the Biome rule into the options of the ESLint rule it is ejected to.
+///
+/// Biome accepts an array of option sets for some rules, each translated into a positional option
+/// of ESLint, as `[level, ...options]`. Only the rules known to have equivalent options are
+/// translated, so the others are emitted with the level only, falling back to the defaults of
+/// ESLint.
+pub fn translate_rule_options(
+    rules_config: &RulesConfiguration,
+    group: &str,
+    rule: &str,
+    eslint_rule: &str,
+) -> Vec<Value> {
+    let Some(options) = serde_json::to_value(rules_config)
+        .ok()
+        .and_then(|rules| rules.get(group)?.get(rule)?.get("options").cloned())
+    else {
+        return Vec::new();
+    };
+
+    translate_options(&options, eslint_rule)
+}
+
+/// Translates the options of a Biome rule, either an option set or an array of them.
+fn translate_options(options: &Value, eslint_rule: &str) -> Vec<Value> {
+    let Some(translate) = TRANSLATORS
+        .iter()
+        .find_map(|(name, translate)| (*name == eslint_rule).then_some(*translate))
+    else {
+        return Vec::new();
+    };
+
+    match options {
+        Value::Array(entries) => entries.iter().filter_map(translate).collect(),
+        options => translate(options).into_iter().collect(),
+    }
+}
+
+// useImportType: { style: "inlineType" } -> { prefer: "type-imports", fixStyle: "inline-type-imports" }
+fn consistent_type_imports(options: &Value) -> Option<Value> {
+    let fix_style = match options.get("style")?.as_str()? {
+        "inlineType" => "inline-type-imports",
+        "separatedType" => "separate-type-imports",
+        // Biome keeps the style of each import as-is, which ESLint has no equivalent for
+        _ => return None,
+    };
+
+    Some(json!({
+        "prefer": "type-imports",
+        "fixStyle": fix_style,
+    }))
+}
+
+// noExcessiveCognitiveComplexity: { maxAllowedComplexity: 10 } -> 10
+fn cognitive_complexity(options: &Value) -> Option<Value> {
+    options.get("maxAllowedComplexity").cloned()
+}
+
+// useConsistentArrayType: { syntax: "generic" } -> { default: "generic" }
+fn array_type(options: &Value) -> Option<Value> {
+    let default = match options.get("syntax")?.as_str()? {
+        "shorthand" => "array",
+        "generic" => "generic",
+        _ => return None,
+    };
+
+    Some(json!({ "default": default }))
+}
+
+// noConsole: { allow: ["error"] } -> { allow: ["error"] }
+fn no_console(options: &Value) -> Option<Value> {
+    let allow = options.get("allow")?.as_array()?;
+
+    Some(json!({ "allow": allow }))
+}
+
+// useFilenamingConvention: { filenameCases: ["kebab-case"] } -> { cases: { kebabCase: true } }
+fn filename_case(options: &Value) -> Option<Value> {
+    let cases: serde_json::Map<String, Value> = options
+        .get("filenameCases")?
+        .as_array()?
+        .iter()
+        .filter_map(|case| {
+            let case = match case.as_str()? {
+                "camelCase" => "camelCase",
+                "kebab-case" => "kebabCase",
+                "PascalCase" => "pascalCase",
+                "snake_case" => "snakeCase",
+                // `export` follows the name of the export, which ESLint has no equivalent for
+                _ => return None,
+            };
+
+            Some((case.to_string(), Value::Bool(true)))
+        })
+        .collect();
+
+    (!cases.is_empty()).then(|| json!({ "cases": cases }))
+}
+
+#[cfg(test)]
+mod tests {
+    use biome_diagnostics::Severity;
+
+    use super::*;
+    use crate::eslint::build_eslint_config;
+    use crate::testing::{config, options, registry};
+
+    /// Translates the options of `style/useImportType` configured by the JSON.
+    fn import_type_options(json: &str) -> Vec<Value> {
+        let rules = config(json).get_linter_rules();
+
+        translate_rule_options(
+            &rules,
+            "style",
+            "useImportType",
+            "@typescript-eslint/consistent-type-imports",
+        )
+    }
+
+    #[test]
+    fn import_styles_are_translated_into_fix_styles() {
+        let options = import_type_options(
+            r#"{ "linter": { "rules": { "style": { "useImportType": { "level": "error", "options": { "style": "inlineType" } } } } } }"#,
+        );
+        assert_eq!(
+            options,
+            [json!({ "prefer": "type-imports", "fixStyle": "inline-type-imports" })]
+        );
+
+        let options = import_type_options(
+            r#"{ "linter": { "rules": { "style": { "useImportType": { "level": "error", "options": { "style": "separatedType" } } } } } }"#,
+        );
+        assert_eq!(
+            options,
+            [json!({ "prefer": "type-imports", "fixStyle": "separate-type-imports" })]
+        );
+    }
+
+    #[test]
+    fn import_styles_without_equivalent_are_left_to_the_defaults() {
+        let options = import_type_options(
+            r#"{ "linter": { "rules": { "style": { "useImportType": { "level": "error", "options": { "style": "auto" } } } } } }"#,
+        );
+        assert!(options.is_empty());
+
+        let options = import_type_options(
+            r#"{ "linter": { "rules": { "style": { "useImportType": "error" } } } }"#,
+        );
+        assert!(options.is_empty());
+    }
+
+    #[test]
+    fn translated_options_follow_the_level() {
+        let registry = registry(&[(
+            "style",
+            "useImportType",
+            Severity::Error,
+            &["@typescript-eslint/consistent-type-imports"],
+        )]);
+        let config = config(
+            r#"{ "linter": { "rules": { "style": { "useImportType": { "level": "error", "options": { "style": "inlineType" } } } } } }"#,
+        );
+
+        let eslint_config = build_eslint_config(&registry, &config, &options())
+            .unwrap()
+            .config;
+        let (_, entry) = eslint_config
+            .split_once(r#""@typescript-eslint/consistent-type-imports": ["#)
+            .unwrap();
+        let (entry, _) = entry.split_once(']').unwrap();
+
+        assert!(entry.trim_start().starts_with(r#""error","#));
+        assert!(entry.contains(r#""type-imports""#));
+        assert!(entry.contains(r#""inline-type-imports""#));
+    }
+
+    #[test]
+    fn every_option_set_is_translated_in_order() {
+        let options = json!([
+            { "filenameCases": ["kebab-case"] },
+            { "filenameCases": ["export"] },
+            { "filenameCases": ["camelCase", "PascalCase"] },
+        ]);
+
+        assert_eq!(
+            translate_options(&options, "unicorn/filename-case"),
+            [
+                json!({ "cases": { "kebabCase": true } }),
+                json!({ "cases": { "camelCase": true, "pascalCase": true } }),
+            ]
+        );
+    }
+
+    #[test]
+    fn single_option_set_is_translated() {
+        assert_eq!(
+            translate_options(&json!({ "allow": ["error"] }), "no-console"),
+            [json!({ "allow": ["error"] })]
+        );
+        assert_eq!(
+            translate_options(
+                &json!({ "maxAllowedComplexity": 10 }),
+                "sonarjs/cognitive-complexity"
+            ),
+            [json!(10)]
+        );
+        assert!(translate_options(&json!({ "allow": ["error"] }), "no-debugger").is_empty());
+    }
+}
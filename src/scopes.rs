@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::diagnostics::warning;
+
+/// Name of the file to customize the ejection, placed next to the Biome config.
+const OPTIONS_FILE: &str = ".biome-eject.json";
+
+/// A config object dedicated to the rules of a group, instead of the global one.
+#[derive(Clone, Debug)]
+pub struct Scope {
+    /// Name of the config object, shown in the debugging tools of ESLint.
+    pub name: Option<String>,
+
+    /// Glob patterns of the files to apply the rules to, or every file if empty.
+    pub files: Vec<String>,
+}
+
+/// Name of the config object isolating the rules of the nursery group.
+pub const NURSERY_SCOPE_NAME: &str = "biome-eject/nursery-experimental";
+
+impl Scope {
+    /// A config object for the nursery rules, applied to every file but apart from the stable ones.
+    pub fn nursery() -> Self {
+        Self {
+            name: Some(NURSERY_SCOPE_NAME.to_string()),
+            files: Vec::new(),
+        }
+    }
+}
+
+/// Loads the scopes of the groups from `.biome-eject.json` in the directory, if any.
+///
+/// ```json
+/// { "scopes": { "a11y": { "name": "a11y", "files": ["**/*.{jsx,tsx}"] } } }
+/// ```
+///
+/// Groups without any scope are configured in the global config object.
+pub fn load_scopes(dir: &Path) -> BTreeMap<String, Scope> {
+    let Ok(contents) = std::fs::read_to_string(dir.join(OPTIONS_FILE)) else {
+        return BTreeMap::new();
+    };
+
+    let options: Value = match serde_json::from_str(&contents) {
+        Ok(options) => options,
+        Err(e) => {
+            warning!("ignoring {OPTIONS_FILE} as it is malformed: {e}");
+            return BTreeMap::new();
+        }
+    };
+
+    let Some(scopes) = options.get("scopes").and_then(Value::as_object) else {
+        return BTreeMap::new();
+    };
+
+    scopes
+        .iter()
+        .filter_map(|(group, scope)| {
+            let files: Vec<_> = scope
+                .get("files")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .map(str::to_string)
+                .collect();
+
+            // Scoping to no files would silently disable the rules of the group
+            if files.is_empty() {
+                warning!("ignoring the scope of {group} without any files");
+                return None;
+            }
+
+            let name = scope
+                .get("name")
+                .and_then(Value::as_str)
+                .map(str::to_string);
+
+            Some((group.clone(), Scope { name, files }))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use biome_diagnostics::Severity;
+
+    use super::*;
+    use crate::eslint::build_eslint_config;
+    use crate::options::EjectOptions;
+    use crate::testing::{config, options, registry, temp_dir};
+
+    #[test]
+    fn scopes_without_files_are_ignored() {
+        let dir = temp_dir("scopes");
+        std::fs::write(
+            dir.join(OPTIONS_FILE),
+            r#"{
+                "scopes": {
+                    "a11y": { "name": "a11y", "files": ["**/*.{jsx,tsx}"] },
+                    "style": { "name": "style" }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let scopes = load_scopes(&dir);
+        assert_eq!(scopes.keys().collect::<Vec<_>>(), ["a11y"]);
+        assert_eq!(scopes["a11y"].name.as_deref(), Some("a11y"));
+        assert_eq!(scopes["a11y"].files, ["**/*.{jsx,tsx}"]);
+    }
+
+    #[test]
+    fn scoped_groups_are_routed_into_their_own_config_object() {
+        let registry = registry(&[
+            (
+                "a11y",
+                "useAltText",
+                Severity::Error,
+                &["jsx-a11y/alt-text"],
+            ),
+            (
+                "suspicious",
+                "noDebugger",
+                Severity::Error,
+                &["no-debugger"],
+            ),
+        ]);
+        let config = config(
+            r#"{
+                "linter": {
+                    "rules": {
+                        "a11y": { "useAltText": "error" },
+                        "suspicious": { "noDebugger": "error" }
+                    }
+                }
+            }"#,
+        );
+        let options = EjectOptions {
+            scopes: BTreeMap::from([(
+                "a11y".to_string(),
+                Scope {
+                    name: Some("a11y".to_string()),
+                    files: vec!["**/*.{jsx,tsx}".to_string()],
+                },
+            )]),
+            ..options()
+        };
+
+        let eslint_config = build_eslint_config(&registry, &config, &options)
+            .unwrap()
+            .config;
+        let (global, scoped) = eslint_config.split_once(r#"name: "a11y""#).unwrap();
+
+        assert!(global.contains(r#""no-debugger": "error""#));
+        assert!(!global.contains(r#""jsx-a11y/alt-text""#));
+
+        assert!(scoped.contains(r#"files: ["**/*.{jsx,tsx}"]"#));
+        assert!(scoped.contains(r#""jsx-a11y/alt-text": "error""#));
+        assert!(!scoped.contains("no-debugger"));
+    }
+}
@@ -0,0 +1,166 @@
+use serde_json::Value;
+
+use crate::diagnostics::warning;
+
+/// Replacement of a script running Biome.
+fn replacement(name: &str, script: &str) -> Option<&'static str> {
+    if !script.contains("biome") {
+        return None;
+    }
+
+    let fix = script.contains("--write") || script.contains("--apply");
+
+    Some(match name {
+        "lint" if fix => "eslint --fix .",
+        "lint" => "eslint .",
+        "format" => "prettier --write .",
+        _ => return None,
+    })
+}
+
+/// Finds where the value of the key starts, searching after the offset.
+fn value_start(source: &str, offset: usize, key: &str) -> Option<usize> {
+    let key = serde_json::to_string(key).unwrap();
+    let mut from = offset;
+
+    // The key is followed by a colon, unlike the same string as a value
+    while let Some(found) = source[from..].find(&key) {
+        let after_key = from + found + key.len();
+        let rest = source[after_key..].trim_start();
+
+        if let Some(value) = rest.strip_prefix(':') {
+            return Some(source.len() - value.trim_start().len());
+        }
+
+        from = after_key;
+    }
+
+    None
+}
+
+/// Rewrites the `lint` and `format` scripts of the `package.json` running Biome into ESLint and
+/// Prettier, leaving the rest of the file as-is.
+///
+/// The scripts are replaced in the source text rather than re-serializing the JSON, which would
+/// lose the order of the keys and the indentation.
+pub fn update_scripts(source: &str) -> String {
+    let Ok(package) = serde_json::from_str::<Value>(source) else {
+        warning!("package.json is not a valid JSON, skipping the scripts");
+        return source.to_string();
+    };
+
+    let Some(scripts) = package.get("scripts").and_then(Value::as_object) else {
+        return source.to_string();
+    };
+
+    // Scripts are searched after the `scripts` key, not to touch the same strings elsewhere
+    let Some(scripts_start) = source.find("\"scripts\"") else {
+        return source.to_string();
+    };
+
+    let mut updated = source.to_string();
+
+    for (name, script) in scripts {
+        let Some(script) = script.as_str() else {
+            continue;
+        };
+
+        let Some(replacement) = replacement(name, script) else {
+            if script.contains("biome") {
+                warning!("the script {name} runs Biome, update it manually: {script}");
+            }
+            continue;
+        };
+
+        // Anchored on the key, as another script may well run the same command
+        let entry = serde_json::to_string(script).unwrap();
+        let Some(start) = value_start(&updated, scripts_start, name)
+            .filter(|&start| updated[start..].starts_with(&entry))
+        else {
+            continue;
+        };
+
+        updated.replace_range(
+            start..start + entry.len(),
+            &serde_json::to_string(replacement).unwrap(),
+        );
+
+        eprintln!("scripts.{name}: {script} -> {replacement}");
+    }
+
+    updated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn biome_scripts_are_replaced_in_place() {
+        let source = r#"{
+  "name": "app",
+  "description": "biome check .",
+  "scripts": {
+    "build": "tsc",
+    "lint": "biome check .",
+    "format": "biome format --write .",
+    "test": "vitest"
+  }
+}
+"#;
+
+        assert_eq!(
+            update_scripts(source),
+            r#"{
+  "name": "app",
+  "description": "biome check .",
+  "scripts": {
+    "build": "tsc",
+    "lint": "eslint .",
+    "format": "prettier --write .",
+    "test": "vitest"
+  }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn fixing_lint_script_is_replaced_with_fix() {
+        let source = r#"{ "scripts": { "lint": "biome lint --write ." } }"#;
+
+        assert_eq!(
+            update_scripts(source),
+            r#"{ "scripts": { "lint": "eslint --fix ." } }"#
+        );
+    }
+
+    #[test]
+    fn other_scripts_are_left_as_is() {
+        let source = r#"{ "scripts": { "ci": "biome ci .", "lint": "eslint ." } }"#;
+
+        assert_eq!(update_scripts(source), source);
+    }
+
+    #[test]
+    fn scripts_are_found_by_their_keys() {
+        let source = r#"{
+  "scripts": {
+    "check": "biome check --write .",
+    "lint": "biome check --write ."
+  }
+}
+"#;
+
+        assert_eq!(
+            update_scripts(source),
+            r#"{
+  "scripts": {
+    "check": "biome check --write .",
+    "lint": "eslint --fix ."
+  }
+}
+"#
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use std::collections::BTreeMap;
+
+use biome_configuration::Configuration;
+use biome_diagnostics::Severity;
+use serde_json::{Map, Value, json};
+
+use crate::eslint::{RuleSourceKind, enabled_rules, get_configured_severity};
+use crate::levels::{LevelCodec, StylelintCodec};
+use crate::options::EjectOptions;
+use crate::output::{apply_newline, resolve_newline};
+use crate::registry::RuleRegistry;
+
+/// Stylelint rules paired with their equivalents in `@eslint/css`.
+const ESLINT_CSS_EQUIVALENTS: &[(&str, &str)] = &[
+    ("at-rule-no-unknown", "no-invalid-at-rules"),
+    ("block-no-empty", "no-empty-blocks"),
+    ("declaration-no-important", "no-important"),
+    (
+        "font-family-no-missing-generic-family-keyword",
+        "font-family-fallbacks",
+    ),
+    ("no-duplicate-at-import-rules", "no-duplicate-imports"),
+    (
+        "no-invalid-position-at-import-rule",
+        "no-invalid-at-rule-placement",
+    ),
+    ("property-no-unknown", "no-invalid-properties"),
+];
+
+/// Returns the name of the `@eslint/css` rule equivalent to the Stylelint rule, if any.
+pub fn eslint_css_equivalent(rule: &str) -> Option<&'static str> {
+    ESLINT_CSS_EQUIVALENTS
+        .iter()
+        .find_map(|(stylelint, eslint)| (*stylelint == rule).then_some(*eslint))
+}
+
+/// Collects the enabled CSS rules keyed by the name of their Stylelint sources.
+pub fn collect_stylelint_rules(
+    registry: &RuleRegistry,
+    config: &Configuration,
+) -> BTreeMap<String, Severity> {
+    let rules_config = config.get_linter_rules();
+    let enabled_rules = enabled_rules(registry, &rules_config);
+
+    let mut rules = BTreeMap::new();
+
+    for (group, registry_rules) in &registry.css_groups {
+        for (rule, info) in registry_rules {
+            if !enabled_rules.contains(&(group, rule)) {
+                continue;
+            }
+
+            let Some(rule_source) = info
+                .sources
+                .iter()
+                .find(|rule_source| rule_source.kind == RuleSourceKind::Stylelint)
+            else {
+                continue;
+            };
+
+            let severity =
+                get_configured_severity(&rules_config, group, rule).unwrap_or(info.severity);
+
+            rules.insert(rule_source.name.clone(), severity);
+        }
+    }
+
+    rules
+}
+
+pub fn build_stylelint_config(
+    registry: &RuleRegistry,
+    config: &Configuration,
+    options: &EjectOptions,
+) -> String {
+    let rules: Map<String, Value> = collect_stylelint_rules(registry, config)
+        .into_iter()
+        .map(|(name, severity)| {
+            (
+                name,
+                StylelintCodec.encode(options.level_mapping.level(severity)),
+            )
+        })
+        .collect();
+
+    let printed = serde_json::to_string_pretty(&json!({ "rules": rules })).unwrap();
+
+    apply_newline(&printed, resolve_newline(options.line_ending, config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eslint::build_eslint_config;
+    use crate::options::CssTarget;
+    use crate::testing::{config, css_registry, options};
+
+    #[test]
+    fn css_rules_are_ejected_into_eslint_css() {
+        let registry = css_registry(&[(
+            "suspicious",
+            "noEmptyBlock",
+            Severity::Warning,
+            &["block-no-empty"],
+        )]);
+        let config =
+            config(r#"{ "linter": { "rules": { "suspicious": { "noEmptyBlock": "error" } } } }"#);
+        let options = EjectOptions {
+            css_target: Some(CssTarget::Eslint),
+            ..options()
+        };
+
+        let eslint_config = build_eslint_config(&registry, &config, &options).unwrap();
+        assert!(eslint_config.packages.contains("@eslint/css"));
+
+        let eslint_config = eslint_config.config;
+        assert!(eslint_config.contains(r#"import css from "@eslint/css";"#));
+        assert!(eslint_config.contains(r#"files: ["**/*.css"]"#));
+        assert!(eslint_config.contains(r#"language: "css/css""#));
+        assert!(eslint_config.contains(r#""css/no-empty-blocks": "error""#));
+    }
+}
@@ -0,0 +1,79 @@
+//! Fixtures shared by the tests of the modules.
+
+use std::path::PathBuf;
+
+use biome_configuration::Configuration;
+use biome_diagnostics::Severity;
+
+use crate::eslint::RuleSourceKind;
+use crate::options::{EjectOptions, Target};
+use crate::registry::{RuleInfo, RuleOrigin, RuleRegistry};
+
+/// Registry of the rules, each inspired by the namespaced ESLint rules, instead of visiting the
+/// analyzers.
+///
+/// The rules are not recommended, so only the ones enabled by the config are ejected.
+pub fn registry(rules: &[(&'static str, &'static str, Severity, &[&str])]) -> RuleRegistry {
+    let mut registry = RuleRegistry::default();
+
+    for &(group, rule, severity, sources) in rules {
+        registry.groups.entry(group).or_default().insert(
+            rule,
+            RuleInfo {
+                severity,
+                recommended: false,
+                sources: sources
+                    .iter()
+                    .map(|name| RuleOrigin::from_namespaced_rule_name(name))
+                    .collect(),
+            },
+        );
+    }
+
+    registry
+}
+
+/// Registry of the CSS rules, each inspired by the Stylelint rules.
+pub fn css_registry(rules: &[(&'static str, &'static str, Severity, &[&str])]) -> RuleRegistry {
+    let mut registry = RuleRegistry {
+        css_groups: registry(rules).groups,
+        ..Default::default()
+    };
+
+    // Stylelint has no namespace to tell its rules from the core rules of ESLint
+    for info in registry
+        .css_groups
+        .values_mut()
+        .flat_map(|rules| rules.values_mut())
+    {
+        for origin in &mut info.sources {
+            origin.kind = RuleSourceKind::Stylelint;
+        }
+    }
+
+    registry
+}
+
+/// Parses the Biome config, which has to name the rules known to Biome.
+pub fn config(json: &str) -> Configuration {
+    serde_json::from_str(json).unwrap()
+}
+
+/// Options of the CLI without any flags.
+pub fn options() -> EjectOptions {
+    EjectOptions {
+        targets: vec![Target::Eslint],
+        output: "eslint.config.mjs".into(),
+        ..Default::default()
+    }
+}
+
+/// Empty directory dedicated to the test, under the temporary directory of the OS.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("biome-eject-{}-{name}", std::process::id()));
+
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    dir
+}
@@ -0,0 +1,60 @@
+//! The normal run writes the files only, leaving stdout to the reports and the dry runs.
+#![cfg(feature = "registry")]
+
+use std::path::PathBuf;
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Empty directory dedicated to the test, apart from the ones of the unit tests.
+fn temp_dir(name: &str) -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let dir = std::env::temp_dir().join(format!(
+        "biome-eject-cli-{}-{}-{name}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+
+    dir
+}
+
+/// Ejects the package with a Biome config, returning the output of the process.
+fn eject(name: &str, args: &[&str]) -> Output {
+    let dir = temp_dir(name);
+    std::fs::write(
+        dir.join("biome.json"),
+        r#"{ "linter": { "rules": { "suspicious": { "noDebugger": "error" } } } }"#,
+    )
+    .unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_biome-eject"))
+        .arg(&dir)
+        .args(["--target", "eslint"])
+        .args(args)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(dir.join("eslint.config.mjs").is_file());
+
+    output
+}
+
+#[test]
+fn normal_run_prints_nothing_to_stdout() {
+    let output = eject("stdout", &[]);
+
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "");
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("suspicious:"));
+}
+
+#[test]
+fn verbose_run_logs_the_groups_to_stderr() {
+    let output = eject("stdout-verbose", &["--verbose"]);
+
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "");
+    assert!(String::from_utf8_lossy(&output.stderr).contains("suspicious:"));
+}